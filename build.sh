@@ -9,6 +9,7 @@ cd "$(dirname "${BASH_SOURCE[0]}")"
 contracts=(
   nft
   organization
+  token
 )
 
 for contract in "${contracts[@]}"; do
@@ -1,32 +1,96 @@
 use scale::{Decode, Encode};
 
-use crate::types::{AccountId, Balance, VotesNumber};
+use crate::types::{AccountId, Balance, Permission, Timestamp, VotesNumber};
 
 /// Possible erroneous results
 #[derive(Debug, PartialEq, Eq, Clone, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum Error {
     AdministrativeFunction,
+    MissingPermission(Permission),
+    PermissionAlreadyGranted,
+    PermissionNotGranted,
     CannotRemoveYourself,
+    LastAdmin,
+    AdminTransferNotExist,
+    CannotRevokeYourself,
+
+    ApprovalRequired,
+    InvalidApprovalThreshold,
+    ApprovalThresholdUnreachable,
+    InvalidActionParameter,
+    ActionNotExist,
+    ActionAlreadyApproved,
+    ActionExpired,
+    ActionNotYetExpired,
 
     MemberAlreadyExists,
     MemberNotExist,
+    MemberIsNotAdmin,
+    MemberIsNotContributor,
+    ImportBatchTooLarge,
+
+    ContributorAlreadySuspended,
+    ContributorNotSuspended,
+    ContributorIsSuspended,
+
+    ApplicationAlreadyExists,
+    ApplicationNotExist,
+    MotivationTooLong,
+
+    DisplayNameTooLong,
+    HandleTooLong,
+    AvatarUriTooLong,
+
+    TeamNotExist,
+    TeamNameTooLong,
+    TeamMemberAlreadyExists,
+    TeamMemberNotExist,
+    TeamLeadAlreadyExists,
+    TeamLeadNotExist,
+    TeamHasActiveRound,
+    TeamNotEmpty,
+    TooManyTeams,
+    ExceedsTeamBudget(Balance),
 
     OnlyContributorCanVote,
     CannotVoteItself,
     YouAreNotContributor,
+    NotEligibleVoter,
 
     InvalidRoundParameter,
+    DurationOutOfRange(Timestamp, Timestamp),
+    InvalidDurationRange,
+    DurationOverflow,
+    CannotLowerRoundParameter,
+    RoundNotExist,
     IsNoActiveRound,
-    IsAnNoFinishedRound,
+    RoundNotStarted,
+    RoundVotingEnded,
+    TooManyActiveRounds,
+    RoundScheduleNotExist,
+    ScheduledRoundNotDue,
+    PreviousRoundNotClosed,
     NotYetFinishedRound,
     InsufficientFunds,
+    RewardAssetMismatch,
+    TokenCallFailed(AccountId),
+    ZeroDeposit,
+    MemoTooLong,
+    InvalidKeeperTip,
+    InvalidCloseGrace,
+    InvalidClaimPeriod,
+    NoRewardToClaim,
+    ClaimPeriodExpired,
+    ClaimPeriodNotExpired,
 
-    MulOverflow(Balance, Balance),
     TransferFailed(AccountId, Balance),
 
     ExceedsVoteLimit(VotesNumber),
     ExceedsYourVoteLimit(VotesNumber),
 
     NftNotSent,
+    BadgesAlreadyIssued,
+
+    SetCodeHashFailed,
 }
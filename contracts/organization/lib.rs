@@ -11,7 +11,9 @@ mod voting;
 
 #[ink::contract]
 mod organization {
-    use ink::prelude::{string::String, vec::Vec};
+    use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::env::DefaultEnvironment;
+    use ink::prelude::{format, string::String, vec::Vec};
     use ink::storage::{Lazy, Mapping};
 
     use scale::alloc::borrow::ToOwned;
@@ -19,9 +21,18 @@ mod organization {
     use nft::Psp34Ref;
 
     use crate::errors::Error;
-    use crate::tools::sqrt_fast;
+    use crate::tools::{page_range, paginate, per_mille, rank, share, sqrt_fast, MAX_PAGE_SIZE};
     use crate::types::{
-        Contributor, Reputation, Role, Round, RoundId, Vote, VoteSign, VotesNumber,
+        ActionId, AdminAction, Application, ApprovalsNumber, Badge, Contributor, DeadlineUnit,
+        DepositTarget, MemberStatus, Parameter, Participation, PendingAction, Permission, Podium,
+        Profile, Psp22Error, Reputation, RewardAsset, Role, Round, RoundId, RoundInfo, RoundResult,
+        RoundSchedule, RoundStatus, Team, TeamId, Treasury, Vote, VoteSign, VotesNumber,
+        BLOCKS_PER_HOUR, DEFAULT_CLAIM_BLOCKS, DEFAULT_CLAIM_PERIOD, DEFAULT_CLOSE_GRACE_BLOCKS,
+        DEFAULT_CLOSE_GRACE_PERIOD, MAX_ACTIVE_ROUNDS, MAX_AVATAR_URI_LENGTH, MAX_CLOSE_PAGE_SIZE,
+        MAX_DISPLAY_NAME_LENGTH, MAX_HANDLE_LENGTH, MAX_IMPORT_BATCH_SIZE,
+        MAX_KEEPER_TIP_PER_MILLE, MAX_MEMO_LENGTH, MAX_MOTIVATION_LENGTH, MAX_PENDING_REWARDS,
+        MAX_TEAMS_PER_MEMBER, MAX_TEAM_NAME_LENGTH, MIN_CLAIM_BLOCKS, MIN_CLAIM_PERIOD,
+        MIN_CLOSE_GRACE_BLOCKS, MIN_CLOSE_GRACE_PERIOD,
     };
     use crate::voting::VoteTrait;
 
@@ -50,7 +61,11 @@ mod organization {
         // ---
         value: Balance,
         max_votes: VotesNumber,
+        start_at: Timestamp,
         finish_at: Timestamp,
+        unit: DeadlineUnit,
+        team: Option<TeamId>,
+        asset: RewardAsset,
     }
 
     /// Close round event.
@@ -59,10 +74,260 @@ mod organization {
         #[ink(topic)]
         round_id: RoundId,
         // ---
-        total_votes: VotesNumber,
+        total_votes: u32,
         total_reputation: Reputation,
     }
 
+    /// Reward claimed event.
+    #[ink(event)]
+    pub struct RewardClaimed {
+        #[ink(topic)]
+        round_id: RoundId,
+        #[ink(topic)]
+        contributor: AccountId,
+        // ---
+        amount: Balance,
+        asset: RewardAsset,
+    }
+
+    /// Unclaimed rewards swept event, they return to the free funds.
+    #[ink(event)]
+    pub struct UnclaimedRewardsSwept {
+        #[ink(topic)]
+        round_id: RoundId,
+        // ---
+        amount: Balance,
+    }
+
+    /// Round closed without contributors to reward event, the funds stay in the treasury.
+    #[ink(event)]
+    pub struct RoundWithoutRecipients {
+        #[ink(topic)]
+        round_id: RoundId,
+        // ---
+        value: Balance,
+    }
+
+    /// Badges of a closed round issued event.
+    #[ink(event)]
+    pub struct BadgesIssued {
+        #[ink(topic)]
+        round_id: RoundId,
+    }
+
+    /// Round closed by a keeper event, anyone can close a round after the grace period.
+    #[ink(event)]
+    pub struct RoundClosedByKeeper {
+        #[ink(topic)]
+        round_id: RoundId,
+        #[ink(topic)]
+        keeper: AccountId,
+        // ---
+        tip: Balance,
+    }
+
+    /// Round amended event, with the previous and the new values.
+    #[ink(event)]
+    pub struct RoundAmended {
+        #[ink(topic)]
+        round_id: RoundId,
+        // ---
+        old_value: Balance,
+        value: Balance,
+        old_max_votes: VotesNumber,
+        max_votes: VotesNumber,
+        old_finish_at: Timestamp,
+        finish_at: Timestamp,
+        unit: DeadlineUnit,
+    }
+
+    /// Round cancelled event.
+    #[ink(event)]
+    pub struct RoundCancelled {
+        #[ink(topic)]
+        round_id: RoundId,
+        // ---
+        reason: String,
+    }
+
+    /// Organization parameter changed event.
+    #[ink(event)]
+    pub struct ParamsChanged {
+        #[ink(topic)]
+        parameter: Parameter,
+        // ---
+        old_value: u64,
+        new_value: u64,
+    }
+
+    /// Permission granted event.
+    #[ink(event)]
+    pub struct PermissionGranted {
+        #[ink(topic)]
+        admin_id: AccountId,
+        // ---
+        permission: Permission,
+    }
+
+    /// Permission revoked event.
+    #[ink(event)]
+    pub struct PermissionRevoked {
+        #[ink(topic)]
+        admin_id: AccountId,
+        // ---
+        permission: Permission,
+    }
+
+    /// Contributor application submitted event.
+    #[ink(event)]
+    pub struct ApplicationSubmitted {
+        #[ink(topic)]
+        applicant_id: AccountId,
+        // ---
+        motivation: String,
+    }
+
+    /// Contributor application approved event.
+    #[ink(event)]
+    pub struct ApplicationApproved {
+        #[ink(topic)]
+        applicant_id: AccountId,
+        #[ink(topic)]
+        admin_id: AccountId,
+    }
+
+    /// Contributor application rejected event.
+    #[ink(event)]
+    pub struct ApplicationRejected {
+        #[ink(topic)]
+        applicant_id: AccountId,
+        #[ink(topic)]
+        admin_id: AccountId,
+        // ---
+        reason: String,
+    }
+
+    /// Contributor suspended event.
+    #[ink(event)]
+    pub struct ContributorSuspended {
+        #[ink(topic)]
+        contributor_id: AccountId,
+        #[ink(topic)]
+        admin_id: AccountId,
+    }
+
+    /// Contributor reinstated event.
+    #[ink(event)]
+    pub struct ContributorReinstated {
+        #[ink(topic)]
+        contributor_id: AccountId,
+        #[ink(topic)]
+        admin_id: AccountId,
+    }
+
+    /// Administrator renounced event.
+    #[ink(event)]
+    pub struct AdminRenounced {
+        #[ink(topic)]
+        admin_id: AccountId,
+    }
+
+    /// Administrator transfer proposed event.
+    #[ink(event)]
+    pub struct AdminTransferProposed {
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+    }
+
+    /// Administrator transfer accepted event.
+    #[ink(event)]
+    pub struct AdminTransferAccepted {
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+    }
+
+    /// Member profile updated event.
+    #[ink(event)]
+    pub struct ProfileUpdated {
+        #[ink(topic)]
+        account_id: AccountId,
+        #[ink(topic)]
+        updated_by: AccountId,
+    }
+
+    /// Members imported event, `count` does not include the accounts already members.
+    #[ink(event)]
+    pub struct MembersImported {
+        count: u32,
+    }
+
+    /// Team created event.
+    #[ink(event)]
+    pub struct TeamCreated {
+        #[ink(topic)]
+        team_id: TeamId,
+        // ---
+        name: String,
+    }
+
+    /// Team deleted event.
+    #[ink(event)]
+    pub struct TeamDeleted {
+        #[ink(topic)]
+        team_id: TeamId,
+    }
+
+    /// Funds deposited event, `memo` is empty for the round fundings.
+    #[ink(event)]
+    pub struct FundsDeposited {
+        #[ink(topic)]
+        donor: AccountId,
+        // ---
+        amount: Balance,
+        target: DepositTarget,
+        memo: String,
+    }
+
+    /// Administrative action proposed event.
+    #[ink(event)]
+    pub struct ActionProposed {
+        #[ink(topic)]
+        action_id: ActionId,
+        #[ink(topic)]
+        proposer: AccountId,
+        // ---
+        expires_at: Timestamp,
+    }
+
+    /// Administrative action approved event.
+    #[ink(event)]
+    pub struct ActionApproved {
+        #[ink(topic)]
+        action_id: ActionId,
+        #[ink(topic)]
+        approver: AccountId,
+        // ---
+        approvals: ApprovalsNumber,
+    }
+
+    /// Administrative action executed event.
+    #[ink(event)]
+    pub struct ActionExecuted {
+        #[ink(topic)]
+        action_id: ActionId,
+    }
+
+    /// Administrative action expired event.
+    #[ink(event)]
+    pub struct ActionExpired {
+        #[ink(topic)]
+        action_id: ActionId,
+    }
+
     //---------------------------------------------------------------------------------//
 
     #[ink(storage)]
@@ -70,21 +335,159 @@ mod organization {
         /// Map with all rounds.
         rounds: Mapping<RoundId, Round>,
 
-        /// Current round of voting, starts at 1,
-        /// limitation: can be active only one round at a time.
-        current_round_id: RoundId,
+        /// Last created round, starts at 1.
+        last_round_id: RoundId,
+
+        /// List of the rounds not yet closed, several rounds can be active at a time,
+        /// at most `MAX_ACTIVE_ROUNDS`.
+        active_rounds: Lazy<Vec<RoundId>>,
+
+        /// Reputation and votes of each voter in each round, it is created with the
+        /// initial values (see `Participation`) on the first vote cast or received.
+        participations: Mapping<(RoundId, AccountId), Participation>,
+
+        /// Indexed list of the voters of each round (from 0 to `voters_count` - 1),
+        /// the contributors with a participation, they are added as they vote.
+        round_voters: Mapping<(RoundId, u32), AccountId>,
+
+        /// Final results of the contributors paid in each closed round.
+        round_results: Mapping<(RoundId, AccountId), RoundResult>,
+
+        /// Number of results of each closed round.
+        round_results_count: Mapping<RoundId, u32>,
+
+        /// Indexed list of the contributors with results in each closed round,
+        /// in the order they were paid, necessary to list them.
+        round_results_by_index: Mapping<(RoundId, u32), AccountId>,
+
+        /// The most reputable contributors paid in each closed round with their reputation,
+        /// at most one per badge, from the highest to the lowest reputation.
+        round_podium: Mapping<RoundId, Podium>,
 
         /// Minimum time for a round
         min_elapsed_milliseconds: Timestamp,
 
+        /// Maximum time for a round, so that the funds are not locked for too long.
+        max_elapsed_milliseconds: Timestamp,
+
+        /// Minimum time for a round with block number deadlines, in blocks.
+        min_elapsed_blocks: BlockNumber,
+
+        /// Maximum time for a round with block number deadlines, in blocks.
+        max_elapsed_blocks: BlockNumber,
+
+        /// Deadline unit of the rounds that do not choose one, set at construction.
+        deadline_unit: DeadlineUnit,
+
+        /// Time after the end of a round from which anyone can close it.
+        close_grace_period: Timestamp,
+
+        /// Blocks after the end of a round (with block number deadlines)
+        /// from which anyone can close it.
+        close_grace_blocks: BlockNumber,
+
+        /// Tip paid from the round funds to whoever closes a round after the grace period,
+        /// in parts per thousand.
+        keeper_tip_per_mille: u16,
+
+        /// Time after the close of a round to claim the rewards.
+        claim_period: Timestamp,
+
+        /// Blocks after the close of a round (with block number deadlines)
+        /// to claim the rewards.
+        claim_blocks: BlockNumber,
+
+        /// Map with the rewards not yet claimed of each round and contributor.
+        rewards: Mapping<(RoundId, AccountId), Balance>,
+
+        /// Map with the rounds with rewards not yet claimed of each contributor,
+        /// necessary to claim them all, at most `MAX_PENDING_REWARDS`.
+        pending_rewards: Mapping<AccountId, Vec<RoundId>>,
+
+        /// Recurring schedule of rounds, `None` if the rounds are opened by hand.
+        round_schedule: Option<RoundSchedule>,
+
+        /// Funds reserved by the rounds not yet closed, they cannot be used by new rounds.
+        reserved_funds: Balance,
+
+        /// Map with the funds reserved in each PSP22 token.
+        reserved_tokens: Mapping<AccountId, Balance>,
+
+        /// Map with the total deposited by each donor.
+        donations: Mapping<AccountId, Balance>,
+
+        /// Total deposited by all the donors.
+        total_donations: Balance,
+
         /// Map with all members and their role.
         members: Mapping<AccountId, Role>,
 
-        /// Map with all contributors and their current reputation.
+        /// Number of members with the admin role.
+        admins_count: u32,
+
+        /// List of all administrators, necessary to list them.
+        admins_list: Lazy<Vec<AccountId>>,
+
+        /// Map with the pending administrator transfers, from the current administrator
+        /// to the account that must accept it.
+        admin_transfers: Mapping<AccountId, AccountId>,
+
+        /// Set of permissions granted to each administrator.
+        permissions: Mapping<(AccountId, Permission), ()>,
+
+        /// Number of administrators holding each permission.
+        permission_holders: Mapping<Permission, u32>,
+
+        /// Number of administrators approvals needed to run an administrative action.
+        approval_threshold: ApprovalsNumber,
+
+        /// Map with all administrative actions awaiting approval.
+        pending_actions: Mapping<ActionId, PendingAction>,
+
+        /// Last proposed administrative action, starts at 1.
+        last_action_id: ActionId,
+
+        /// Map with all contributors and their status.
         contributors: Mapping<AccountId, Contributor>,
 
-        /// List of all contributors, necessary to distribute the funds.
-        contributors_list: Lazy<Vec<AccountId>>,
+        /// Number of contributors.
+        contributors_count: u32,
+
+        /// Indexed list of all contributors (from 0 to `contributors_count` - 1),
+        /// necessary to distribute the funds.
+        contributors_by_index: Mapping<u32, AccountId>,
+
+        /// Map with the index of each contributor in `contributors_by_index`.
+        contributors_index: Mapping<AccountId, u32>,
+
+        /// Map with all teams.
+        teams: Mapping<TeamId, Team>,
+
+        /// Last created team, starts at 1.
+        last_team_id: TeamId,
+
+        /// Members of each team with the last round created when they joined,
+        /// a contributor can be in several teams.
+        team_members: Mapping<(TeamId, AccountId), RoundId>,
+
+        /// Set of leads of each team, who manage the team members and rounds.
+        team_leads: Mapping<(TeamId, AccountId), ()>,
+
+        /// Map with the teams of each account, as member or lead,
+        /// necessary to leave them when the account is removed.
+        member_teams: Mapping<AccountId, Vec<TeamId>>,
+
+        /// Map with the profiles of the members.
+        profiles: Mapping<AccountId, Profile>,
+
+        /// Map with all pending applications to become a contributor.
+        applications: Mapping<AccountId, Application>,
+
+        /// Indexed list of all pending applicants, necessary to list them, with the index
+        /// of each applicant and the number of applicants, constant cost add and remove.
+        applications_by_index: Mapping<u32, AccountId>,
+        applications_index: Mapping<AccountId, u32>,
+        applications_count: u32,
 
         /// Reference to the NFT contract, which is the proof of vote.
         nft_ref: Psp34Ref,
@@ -113,40 +516,127 @@ mod organization {
         }
     }
 
+    /// Function that converts hours to milliseconds, checking the overflow.
+    fn hours_to_milliseconds(hours: u32) -> Result<Timestamp> {
+        Timestamp::from(hours)
+            .checked_mul(60 * 60 * 1000)
+            .ok_or(Error::DurationOverflow)
+    }
+
+    /// Function that converts hours to blocks (see `BLOCKS_PER_HOUR`), checking the overflow.
+    fn hours_to_blocks(hours: u32) -> Result<BlockNumber> {
+        hours
+            .checked_mul(BLOCKS_PER_HOUR)
+            .ok_or(Error::DurationOverflow)
+    }
+
+    /// Function that checks the length limits of the profile fields.
+    fn validate_profile(profile: &Profile) -> Result<()> {
+        if profile.display_name.len() > MAX_DISPLAY_NAME_LENGTH {
+            return Err(Error::DisplayNameTooLong);
+        }
+        if profile.handle.len() > MAX_HANDLE_LENGTH {
+            return Err(Error::HandleTooLong);
+        }
+        if profile.avatar_uri.len() > MAX_AVATAR_URI_LENGTH {
+            return Err(Error::AvatarUriTooLong);
+        }
+        Ok(())
+    }
+
     /////////////////////////////////////////////////////////////////////////////////////
 
     impl Organization {
         /// The constructor initializes the organization,
         /// including the administrator as a Admin member and instantiates the nft contract.
+        /// The rounds use `deadline_unit` unless they choose another one,
+        /// and they last between `min_elapsed_hours` and `max_elapsed_hours`.
         #[ink(constructor)]
         pub fn new(
             administrator_id: AccountId,
             nft_code_hash: Hash,
             min_elapsed_hours: u32,
-        ) -> Self {
+            max_elapsed_hours: u32,
+            deadline_unit: DeadlineUnit,
+        ) -> Result<Self> {
+            if min_elapsed_hours > max_elapsed_hours {
+                return Err(Error::InvalidDurationRange);
+            }
+
             let rounds = Mapping::default();
-            let mut members = Mapping::default();
+            let members = Mapping::default();
             let contributors = Mapping::default();
-            let mut contributors_list = Lazy::new();
+            let mut active_rounds = Lazy::new();
+            let mut admins_list = Lazy::new();
 
-            members.insert(administrator_id, &Role::Admin);
-            contributors_list.set(&Vec::new());
+            active_rounds.set(&Vec::new());
+            admins_list.set(&Vec::new());
 
-            let min_elapsed_milliseconds = (min_elapsed_hours * 60 * 60 * 1000) as Timestamp;
+            let min_elapsed_milliseconds = hours_to_milliseconds(min_elapsed_hours)?;
+            let max_elapsed_milliseconds = hours_to_milliseconds(max_elapsed_hours)?;
+            let min_elapsed_blocks = hours_to_blocks(min_elapsed_hours)?;
+            let max_elapsed_blocks = hours_to_blocks(max_elapsed_hours)?;
 
-            Self {
+            let mut instance = Self {
                 rounds,
                 members,
                 contributors,
-                contributors_list,
-                current_round_id: 0,
+                contributors_count: 0,
+                contributors_by_index: Mapping::default(),
+                contributors_index: Mapping::default(),
+                last_round_id: 0,
+                active_rounds,
+                participations: Mapping::default(),
+                round_voters: Mapping::default(),
+                round_results: Mapping::default(),
+                round_results_count: Mapping::default(),
+                round_results_by_index: Mapping::default(),
+                round_podium: Mapping::default(),
                 min_elapsed_milliseconds,
+                max_elapsed_milliseconds,
+                min_elapsed_blocks,
+                max_elapsed_blocks,
+                deadline_unit,
+                close_grace_period: DEFAULT_CLOSE_GRACE_PERIOD,
+                close_grace_blocks: DEFAULT_CLOSE_GRACE_BLOCKS,
+                keeper_tip_per_mille: 0,
+                claim_period: DEFAULT_CLAIM_PERIOD,
+                claim_blocks: DEFAULT_CLAIM_BLOCKS,
+                rewards: Mapping::default(),
+                pending_rewards: Mapping::default(),
+                reserved_funds: 0,
+                reserved_tokens: Mapping::default(),
+                donations: Mapping::default(),
+                total_donations: 0,
+                round_schedule: None,
+                admins_count: 0,
+                admins_list,
+                admin_transfers: Mapping::default(),
+                permissions: Mapping::default(),
+                permission_holders: Mapping::default(),
+                approval_threshold: 1,
+                pending_actions: Mapping::default(),
+                last_action_id: 0,
+                teams: Mapping::default(),
+                last_team_id: 0,
+                team_members: Mapping::default(),
+                team_leads: Mapping::default(),
+                member_teams: Mapping::default(),
+                profiles: Mapping::default(),
+                applications: Mapping::default(),
+                applications_by_index: Mapping::default(),
+                applications_index: Mapping::default(),
+                applications_count: 0,
                 nft_ref: Psp34Ref::new()
                     .code_hash(nft_code_hash)
                     .endowment(0)
                     .salt_bytes(Vec::new())
                     .instantiate(),
-            }
+            };
+
+            instance.add_member(administrator_id, Role::Admin);
+
+            Ok(instance)
         }
 
         // ------------------------------------------------------------------------------
@@ -154,258 +644,2705 @@ mod organization {
         fn add_member(&mut self, contributor_id: AccountId, role: Role) {
             self.members.insert(contributor_id, &role);
 
+            if self.applications.contains(contributor_id) {
+                self.rem_application(contributor_id);
+            }
+
+            if role == Role::Admin {
+                self.admins_count += 1;
+
+                let mut list = self.admins_list.get().unwrap();
+                list.push(contributor_id);
+                self.admins_list.set(&list);
+
+                for permission in Permission::ALL {
+                    self.add_permission(contributor_id, permission);
+                }
+            }
+
             if role == Role::Contributor {
                 self.contributors.insert(
                     contributor_id,
                     &Contributor {
-                        reputation: 1,
-                        votes_submitted: 0,
+                        status: MemberStatus::Active,
+                        joined_round: self.last_round_id,
                     },
                 );
 
-                let mut list = self.contributors_list.get().unwrap();
-                list.push(contributor_id);
-                self.contributors_list.set(&list);
+                self.push_contributor(contributor_id);
             }
         }
 
         fn rem_member(&mut self, contributor_id: AccountId) {
-            self.members.remove(contributor_id);
+            if self.members.get(contributor_id) == Some(Role::Admin) {
+                self.admins_count -= 1;
+                self.admin_transfers.remove(contributor_id);
+
+                let mut list = self.admins_list.get().unwrap();
+                list.retain(|x| *x != contributor_id);
+                self.admins_list.set(&list);
+
+                for permission in Permission::ALL {
+                    if self.permissions.contains((contributor_id, permission)) {
+                        self.rem_permission(contributor_id, permission);
+                    }
+                }
+            }
 
-            self.contributors.remove(contributor_id);
+            self.members.remove(contributor_id);
 
-            let mut list = self.contributors_list.get().unwrap();
-            list.retain(|x| *x != contributor_id);
-            self.contributors_list.set(&list);
-        }
+            self.profiles.remove(contributor_id);
 
-        fn is_caller_admin(&self) -> Result<()> {
-            let caller_id = self.env().caller();
-            let caller_member = self.members.get(caller_id);
+            for team_id in self.member_teams.take(contributor_id).unwrap_or_default() {
+                // a team with members or leads cannot be deleted, unwrap is safe here
+                let mut team = self.teams.get(team_id).unwrap();
 
-            if caller_member.is_none() || caller_member.unwrap() != Role::Admin {
-                return Err(Error::AdministrativeFunction);
+                if self.team_leads.take((team_id, contributor_id)).is_some() {
+                    team.leads_count -= 1;
+                }
+                if self.team_members.take((team_id, contributor_id)).is_some() {
+                    team.members_count -= 1;
+                }
+                self.teams.insert(team_id, &team);
             }
 
-            Ok(())
+            self.contributors.remove(contributor_id);
+
+            self.swap_remove_contributor(contributor_id);
         }
 
-        fn is_active_round(&self) -> Result<()> {
-            if let Some(round) = self.rounds.get(self.current_round_id) {
-                if !round.is_finished {
-                    return Err(Error::IsAnNoFinishedRound);
+        /// Adds the team to the teams of the account, at most `MAX_TEAMS_PER_MEMBER`.
+        fn push_member_team(&mut self, account_id: AccountId, team_id: TeamId) -> Result<()> {
+            let mut teams = self.member_teams.get(account_id).unwrap_or_default();
+
+            if !teams.contains(&team_id) {
+                if teams.len() >= MAX_TEAMS_PER_MEMBER {
+                    return Err(Error::TooManyTeams);
                 }
+                teams.push(team_id);
+                self.member_teams.insert(account_id, &teams);
             }
             Ok(())
         }
 
-        fn send_nft(
-            &mut self,
-            category: &str,
-            contributor: Option<(AccountId, Reputation)>,
-        ) -> Result<()> {
-            if let Some(contributor) = contributor {
-                self.nft_ref
-                    .mint_to(category.to_owned(), contributor.0)
-                    .map_err(|_| Error::NftNotSent)?;
+        /// Removes the team from the teams of the account once it is neither member nor lead.
+        fn rem_member_team(&mut self, account_id: AccountId, team_id: TeamId) {
+            if self.team_members.contains((team_id, account_id))
+                || self.team_leads.contains((team_id, account_id))
+            {
+                return;
             }
-            Ok(())
+
+            let mut teams = self.member_teams.get(account_id).unwrap_or_default();
+            teams.retain(|id| *id != team_id);
+            self.member_teams.insert(account_id, &teams);
         }
 
-        // ------------------------------------------------------------------------------
+        /// Appends the contributor to the indexed list, constant cost.
+        fn push_contributor(&mut self, contributor_id: AccountId) {
+            let index = self.contributors_count;
+            self.contributors_by_index.insert(index, &contributor_id);
+            self.contributors_index.insert(contributor_id, &index);
+            self.contributors_count += 1;
+        }
 
-        /// Administrative function: adding a administrator.
-        #[ink(message)]
-        pub fn add_admin(&mut self, contributor_id: AccountId) -> Result<()> {
-            self.is_caller_admin()?;
+        /// Removes the contributor from the indexed list moving the last contributor
+        /// to its place, constant cost.
+        fn swap_remove_contributor(&mut self, contributor_id: AccountId) {
+            let Some(index) = self.contributors_index.take(contributor_id) else {
+                return;
+            };
 
-            if self.members.contains(contributor_id) {
-                return Err(Error::MemberAlreadyExists);
+            let last_index = self.contributors_count - 1;
+
+            if index != last_index {
+                // unwrap is safe here
+                let last_id = self.contributors_by_index.get(last_index).unwrap();
+                self.contributors_by_index.insert(index, &last_id);
+                self.contributors_index.insert(last_id, &index);
             }
 
-            self.add_member(contributor_id, Role::Admin);
+            self.contributors_by_index.remove(last_index);
+            self.contributors_count -= 1;
+        }
 
-            Ok(())
+        fn contributors_page(&self, offset: u32, limit: u32) -> Vec<AccountId> {
+            page_range(offset, limit, self.contributors_count)
+                .map(|index| self.contributors_by_index.get(index).unwrap()) // unwrap is safe here
+                .collect()
         }
 
-        /// Administrative function: removing a administrator.
-        #[ink(message)]
-        pub fn rem_admin(&mut self, contributor_id: AccountId) -> Result<()> {
-            self.is_caller_admin()?;
+        fn push_application(&mut self, applicant_id: AccountId, application: &Application) {
+            self.applications.insert(applicant_id, application);
 
-            if !self.members.contains(contributor_id) {
-                return Err(Error::MemberNotExist);
-            }
+            let index = self.applications_count;
+            self.applications_by_index.insert(index, &applicant_id);
+            self.applications_index.insert(applicant_id, &index);
+            self.applications_count += 1;
+        }
 
-            if self.env().caller() == contributor_id {
-                // this prevents the contract from running out of administrators
-                return Err(Error::CannotRemoveYourself);
-            }
+        /// Removes the application moving the last applicant to its place, constant cost.
+        fn rem_application(&mut self, applicant_id: AccountId) {
+            self.applications.remove(applicant_id);
 
-            self.rem_member(contributor_id);
+            let Some(index) = self.applications_index.take(applicant_id) else {
+                return;
+            };
+
+            let last_index = self.applications_count - 1;
+
+            if index != last_index {
+                // unwrap is safe here
+                let last_id = self.applications_by_index.get(last_index).unwrap();
+                self.applications_by_index.insert(index, &last_id);
+                self.applications_index.insert(last_id, &index);
+            }
+
+            self.applications_by_index.remove(last_index);
+            self.applications_count -= 1;
+        }
+
+        fn add_permission(&mut self, admin_id: AccountId, permission: Permission) {
+            self.permissions.insert((admin_id, permission), &());
+
+            let holders = self.permission_holders.get(permission).unwrap_or(0);
+            self.permission_holders.insert(permission, &(holders + 1));
+
+            self.env().emit_event(PermissionGranted {
+                admin_id,
+                permission,
+            });
+        }
+
+        fn rem_permission(&mut self, admin_id: AccountId, permission: Permission) {
+            self.permissions.remove((admin_id, permission));
+
+            let holders = self.permission_holders.get(permission).unwrap_or(0);
+            self.permission_holders
+                .insert(permission, &holders.saturating_sub(1));
+
+            self.env().emit_event(PermissionRevoked {
+                admin_id,
+                permission,
+            });
+        }
+
+        fn is_caller_allowed(&self, permission: Permission) -> Result<()> {
+            let caller_id = self.env().caller();
+            let caller_member = self.members.get(caller_id);
+
+            if caller_member.is_none() || caller_member.unwrap() != Role::Admin {
+                return Err(Error::AdministrativeFunction);
+            }
+
+            if !self.permissions.contains((caller_id, permission)) {
+                return Err(Error::MissingPermission(permission));
+            }
+
+            Ok(())
+        }
+
+        /// Checks that the organization does not run out of administrators
+        /// and that the approval threshold is still reachable without the administrator.
+        fn is_admin_removable(&self, admin_id: AccountId) -> Result<()> {
+            if self.admins_count <= 1 {
+                return Err(Error::LastAdmin);
+            }
+
+            if self.admins_count <= self.approval_threshold.into() {
+                return Err(Error::ApprovalThresholdUnreachable);
+            }
+
+            // this prevents pending and future actions from being unable to run
+            self.is_threshold_reachable_without(admin_id, &Permission::ALL)
+        }
+
+        /// Checks that the approval threshold is still reachable for every action
+        /// if the administrator loses the given permissions.
+        fn is_threshold_reachable_without(
+            &self,
+            admin_id: AccountId,
+            permissions: &[Permission],
+        ) -> Result<()> {
+            for permission in permissions {
+                let holders = self.permission_holders.get(permission).unwrap_or(0);
+
+                if self.permissions.contains((admin_id, *permission))
+                    && holders <= self.approval_threshold.into()
+                {
+                    return Err(Error::ApprovalThresholdUnreachable);
+                }
+            }
+            Ok(())
+        }
+
+        fn is_single_approval(&self) -> Result<()> {
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            Ok(())
+        }
+
+        /// Current time in the given unit, timestamp in milliseconds or block number.
+        fn now_in(&self, unit: DeadlineUnit) -> Timestamp {
+            match unit {
+                DeadlineUnit::Timestamp => self.env().block_timestamp(),
+                DeadlineUnit::BlockNumber => self.env().block_number().into(),
+            }
+        }
+
+        /// Minimum and maximum time for a round in the given unit.
+        fn elapsed_range_in(&self, unit: DeadlineUnit) -> (Timestamp, Timestamp) {
+            match unit {
+                DeadlineUnit::Timestamp => {
+                    (self.min_elapsed_milliseconds, self.max_elapsed_milliseconds)
+                }
+                DeadlineUnit::BlockNumber => (
+                    self.min_elapsed_blocks.into(),
+                    self.max_elapsed_blocks.into(),
+                ),
+            }
+        }
+
+        fn is_duration_in_range(&self, unit: DeadlineUnit, duration: Timestamp) -> Result<()> {
+            let (min, max) = self.elapsed_range_in(unit);
+
+            if duration < min || duration > max {
+                return Err(Error::DurationOutOfRange(min, max));
+            }
+            Ok(())
+        }
+
+        /// The duration of a round is counted from the start of the voting, but its funds
+        /// are reserved from now, so it must also finish within the maximum time from now.
+        fn is_round_in_range(
+            &self,
+            unit: DeadlineUnit,
+            start_at: Timestamp,
+            finish_at: Timestamp,
+        ) -> Result<()> {
+            let now = self.now_in(unit);
+            let voting_from = start_at.max(now);
+
+            self.is_duration_in_range(unit, finish_at.saturating_sub(voting_from))?;
+
+            let (min, max) = self.elapsed_range_in(unit);
+
+            if finish_at.saturating_sub(now) > max {
+                return Err(Error::DurationOutOfRange(min, max));
+            }
+            Ok(())
+        }
+
+        /// Funds available for new rounds, the balance without the existential deposit
+        /// and the reserved funds.
+        fn free_funds(&self) -> Balance {
+            self.env()
+                .balance()
+                .saturating_sub(self.env().minimum_balance())
+                .saturating_sub(self.reserved_funds)
+        }
+
+        /// Tokens available for new rounds, the balance of the organization in the token
+        /// without the reserved funds.
+        fn free_tokens(&self, token: AccountId) -> Result<Balance> {
+            let reserved = self.reserved_tokens.get(token).unwrap_or(0);
+            Ok(self.token_balance(token)?.saturating_sub(reserved))
+        }
+
+        fn reserve_funds(&mut self, asset: RewardAsset, value: Balance) -> Result<()> {
+            match asset {
+                RewardAsset::Native => {
+                    if value > self.free_funds() {
+                        return Err(Error::InsufficientFunds);
+                    }
+                    self.reserved_funds += value;
+                }
+                RewardAsset::Psp22(token) => {
+                    if value > self.free_tokens(token)? {
+                        return Err(Error::InsufficientFunds);
+                    }
+                    let reserved = self.reserved_tokens.get(token).unwrap_or(0);
+                    self.reserved_tokens
+                        .insert(token, &reserved.saturating_add(value));
+                }
+            }
+            Ok(())
+        }
+
+        fn release_funds(&mut self, asset: RewardAsset, value: Balance) {
+            match asset {
+                RewardAsset::Native => {
+                    self.reserved_funds = self.reserved_funds.saturating_sub(value);
+                }
+                RewardAsset::Psp22(token) => {
+                    let reserved = self.reserved_tokens.get(token).unwrap_or(0);
+                    self.reserved_tokens
+                        .insert(token, &reserved.saturating_sub(value));
+                }
+            }
+        }
+
+        /// Balance of the organization in a PSP22 token, with a cross-contract call.
+        fn token_balance(&self, token: AccountId) -> Result<Balance> {
+            build_call::<DefaultEnvironment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("PSP22::balance_of")))
+                        .push_arg(self.env().account_id()),
+                )
+                .returns::<Balance>()
+                .try_invoke()
+                .map_err(|_| Error::TokenCallFailed(token))?
+                .map_err(|_| Error::TokenCallFailed(token))
+        }
+
+        /// Transfers funds of the organization in the native currency
+        /// or in a PSP22 token, with a cross-contract call.
+        fn transfer_asset(&self, asset: RewardAsset, to: AccountId, amount: Balance) -> Result<()> {
+            let transferred = match asset {
+                RewardAsset::Native => self.env().transfer(to, amount).is_ok(),
+                RewardAsset::Psp22(token) => build_call::<DefaultEnvironment>()
+                    .call(token)
+                    .exec_input(
+                        ExecutionInput::new(Selector::new(ink::selector_bytes!("PSP22::transfer")))
+                            .push_arg(to)
+                            .push_arg(amount)
+                            .push_arg(Vec::<u8>::new()),
+                    )
+                    .returns::<core::result::Result<(), Psp22Error>>()
+                    .try_invoke()
+                    .is_ok_and(|result| result.is_ok_and(|transfer| transfer.is_ok())),
+            };
+
+            if !transferred {
+                return Err(Error::TransferFailed(to, amount));
+            }
+            Ok(())
+        }
+
+        fn add_donation(&mut self, target: DepositTarget, memo: String) -> Result<Balance> {
+            let donor = self.env().caller();
+            let amount = self.env().transferred_value();
+
+            if amount == 0 {
+                return Err(Error::ZeroDeposit);
+            }
+
+            let total = self.donations.get(donor).unwrap_or(0);
+            self.donations.insert(donor, &total.saturating_add(amount));
+            self.total_donations = self.total_donations.saturating_add(amount);
+
+            self.env().emit_event(FundsDeposited {
+                donor,
+                amount,
+                target,
+                memo,
+            });
+
+            Ok(amount)
+        }
+
+        fn emit_params_changed(&self, parameter: Parameter, old_value: u64, new_value: u64) {
+            self.env().emit_event(ParamsChanged {
+                parameter,
+                old_value,
+                new_value,
+            });
+        }
+
+        fn claim_period_in(&self, unit: DeadlineUnit) -> Timestamp {
+            match unit {
+                DeadlineUnit::Timestamp => self.claim_period,
+                DeadlineUnit::BlockNumber => self.claim_blocks.into(),
+            }
+        }
+
+        /// Takes the reward of a contributor in a round with its asset, returns `None`
+        /// if the claim period has expired, the reward is released from the reserved funds.
+        fn take_reward(
+            &mut self,
+            round_id: RoundId,
+            contributor_id: AccountId,
+        ) -> Option<(RewardAsset, Balance)> {
+            let amount = self.rewards.take((round_id, contributor_id))?;
+            // the round exists, it was closed with this reward
+            let mut round = self.rounds.get(round_id).unwrap();
+
+            if round.is_claim_expired_at(self.now_in(round.unit)) {
+                return None;
+            }
+
+            round.unclaimed = round.unclaimed.saturating_sub(amount);
+            self.rounds.insert(round_id, &round);
+            self.release_funds(round.asset, amount);
+
+            self.env().emit_event(RewardClaimed {
+                round_id,
+                contributor: contributor_id,
+                amount,
+                asset: round.asset,
+            });
+
+            Some((round.asset, amount))
+        }
+
+        fn is_round_finished(&self, round_id: RoundId) -> bool {
+            self.rounds
+                .get(round_id)
+                .is_some_and(|round| round.status == RoundStatus::Finished)
+        }
+
+        fn is_claim_expired(&self, round_id: RoundId) -> bool {
+            self.rounds.get(round_id).is_some_and(|round| {
+                round.status == RoundStatus::Finished
+                    && round.is_claim_expired_at(self.now_in(round.unit))
+            })
+        }
+
+        /// Adds the round to the pending rewards of the contributor, the expired rounds are
+        /// discarded when the list is full. If it is still full the round is left out,
+        /// its reward can still be claimed with `claim_reward`.
+        fn push_pending_reward(&mut self, contributor_id: AccountId, round_id: RoundId) {
+            let mut pending = self.pending_rewards.get(contributor_id).unwrap_or_default();
+
+            if pending.len() >= MAX_PENDING_REWARDS {
+                pending.retain(|id| !self.is_claim_expired(*id));
+            }
+
+            if pending.len() < MAX_PENDING_REWARDS {
+                pending.push(round_id);
+            }
+            self.pending_rewards.insert(contributor_id, &pending);
+        }
+
+        fn close_grace_in(&self, unit: DeadlineUnit) -> Timestamp {
+            match unit {
+                DeadlineUnit::Timestamp => self.close_grace_period,
+                DeadlineUnit::BlockNumber => self.close_grace_blocks.into(),
+            }
+        }
+
+        /// Gets the round if it is open for voting.
+        fn get_open_round(&self, round_id: RoundId) -> Result<Round> {
+            let round = self.rounds.get(round_id).ok_or(Error::RoundNotExist)?;
+
+            match round.status_at(self.now_in(round.unit)) {
+                RoundStatus::Voting => Ok(round),
+                RoundStatus::Scheduled => Err(Error::RoundNotStarted),
+                RoundStatus::AwaitingClose => Err(Error::RoundVotingEnded),
+                RoundStatus::Closing | RoundStatus::Finished | RoundStatus::Cancelled => {
+                    Err(Error::IsNoActiveRound)
+                }
+            }
+        }
+
+        /// Only the contributors (of the team) that joined before the round was created vote.
+        fn is_eligible_voter(
+            &self,
+            round: &Round,
+            round_id: RoundId,
+            account_id: AccountId,
+        ) -> Result<()> {
+            if !self.is_round_member(round, round_id, account_id) {
+                return Err(Error::NotEligibleVoter);
+            }
+            Ok(())
+        }
+
+        /// Whether the account is a contributor (and a member of the team of the round)
+        /// since before the round was created. The additions take effect from the next round
+        /// and the removals immediately, also for the voters that already voted.
+        fn is_round_member(&self, round: &Round, round_id: RoundId, account_id: AccountId) -> bool {
+            let joined_before = |joined_round: RoundId| joined_round < round_id;
+
+            let is_contributor = self
+                .contributors
+                .get(account_id)
+                .is_some_and(|contributor| joined_before(contributor.joined_round));
+
+            let is_team_member = match round.team {
+                Some(team_id) => self
+                    .team_members
+                    .get((team_id, account_id))
+                    .is_some_and(joined_before),
+                None => true,
+            };
+
+            is_contributor && is_team_member
+        }
+
+        /// Appends the contributor to the voters of the round on its first vote, cast or received.
+        fn push_round_voter(
+            &mut self,
+            round_id: RoundId,
+            round: &mut Round,
+            account_id: AccountId,
+        ) {
+            if !self.participations.contains((round_id, account_id)) {
+                self.round_voters
+                    .insert((round_id, round.voters_count), &account_id);
+                round.voters_count += 1;
+                round.total_reputation = round
+                    .total_reputation
+                    .saturating_add(Participation::default().reputation);
+            }
+        }
+
+        fn get_participation(&self, round_id: RoundId, contributor_id: AccountId) -> Participation {
+            self.participations
+                .get((round_id, contributor_id))
+                .unwrap_or_default()
+        }
+
+        /// The leads of the team of the round manage it without approvals,
+        /// otherwise the permission and a single approval are needed.
+        fn is_caller_round_lead_or_manager(&self, round_id: RoundId) -> Result<()> {
+            let team = self.rounds.get(round_id).and_then(|round| round.team);
+
+            let is_team_lead = team
+                .is_some_and(|team_id| self.team_leads.contains((team_id, self.env().caller())));
+
+            if !is_team_lead {
+                self.is_caller_allowed(Permission::RoundManager)?;
+                self.is_single_approval()?;
+            }
+            Ok(())
+        }
+
+        fn get_round_info(&self, round_id: RoundId) -> Option<RoundInfo> {
+            self.rounds.get(round_id).map(|round| {
+                let now = self.now_in(round.unit);
+                let status = round.status_at(now);
+                let time_remaining = match status {
+                    RoundStatus::Scheduled | RoundStatus::Voting => {
+                        round.finish_at.saturating_sub(now)
+                    }
+                    _ => 0,
+                };
+
+                RoundInfo {
+                    round_id,
+                    round,
+                    status,
+                    time_remaining,
+                }
+            })
+        }
+
+        fn rem_active_round(&mut self, round_id: RoundId) {
+            let mut active_rounds = self.active_rounds.get().unwrap();
+            active_rounds.retain(|id| *id != round_id);
+            self.active_rounds.set(&active_rounds);
+        }
+
+        /// The team leads manage their team, otherwise the permission is needed.
+        fn is_caller_team_lead_or_allowed(
+            &self,
+            team_id: TeamId,
+            permission: Permission,
+        ) -> Result<()> {
+            if self.team_leads.contains((team_id, self.env().caller())) {
+                return Ok(());
+            }
+            self.is_caller_allowed(permission)
+        }
+
+        fn find_team(&self, team_id: TeamId) -> Result<Team> {
+            self.teams.get(team_id).ok_or(Error::TeamNotExist)
+        }
+
+        fn get_contributor(&self, contributor_id: AccountId) -> Result<Contributor> {
+            match self.members.get(contributor_id) {
+                None => Err(Error::MemberNotExist),
+                Some(Role::Admin) => Err(Error::MemberIsNotContributor),
+                // unwrap is safe here
+                Some(Role::Contributor) => Ok(self.contributors.get(contributor_id).unwrap()),
+            }
+        }
+
+        fn update_profile(&mut self, account_id: AccountId, profile: Profile) -> Result<()> {
+            if !self.members.contains(account_id) {
+                return Err(Error::MemberNotExist);
+            }
+
+            validate_profile(&profile)?;
+
+            self.profiles.insert(account_id, &profile);
+
+            self.env().emit_event(ProfileUpdated {
+                account_id,
+                updated_by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        fn send_nft(&mut self, badge: Badge, contributor_id: AccountId) -> Result<()> {
+            self.nft_ref
+                .mint_to(badge.category().to_owned(), contributor_id)
+                .map_err(|_| Error::NftNotSent)
+        }
+
+        fn push_round_result(
+            &mut self,
+            round_id: RoundId,
+            contributor_id: AccountId,
+            result: &RoundResult,
+        ) {
+            let index = self.round_results_count.get(round_id).unwrap_or(0);
+            self.round_results_by_index
+                .insert((round_id, index), &contributor_id);
+            self.round_results_count.insert(round_id, &(index + 1));
+            self.round_results
+                .insert((round_id, contributor_id), result);
+        }
+
+        // ------------------------------------------------------------------------------
+
+        fn do_add_admin(&mut self, contributor_id: AccountId) -> Result<()> {
+            if self.members.contains(contributor_id) {
+                return Err(Error::MemberAlreadyExists);
+            }
+
+            self.add_member(contributor_id, Role::Admin);
+
+            Ok(())
+        }
+
+        fn do_rem_admin(&mut self, contributor_id: AccountId) -> Result<()> {
+            let member = self.members.get(contributor_id);
+
+            if member.is_none() {
+                return Err(Error::MemberNotExist);
+            }
+
+            if member == Some(Role::Admin) {
+                self.is_admin_removable(contributor_id)?;
+            }
+
+            self.rem_member(contributor_id);
+
+            Ok(())
+        }
+
+        fn do_grant_permission(
+            &mut self,
+            admin_id: AccountId,
+            permission: Permission,
+        ) -> Result<()> {
+            match self.members.get(admin_id) {
+                None => return Err(Error::MemberNotExist),
+                Some(Role::Contributor) => return Err(Error::MemberIsNotAdmin),
+                Some(Role::Admin) => {}
+            }
+
+            if self.permissions.contains((admin_id, permission)) {
+                return Err(Error::PermissionAlreadyGranted);
+            }
+
+            self.add_permission(admin_id, permission);
+
+            Ok(())
+        }
+
+        fn do_revoke_permission(
+            &mut self,
+            admin_id: AccountId,
+            permission: Permission,
+        ) -> Result<()> {
+            if !self.permissions.contains((admin_id, permission)) {
+                return Err(Error::PermissionNotGranted);
+            }
+
+            // this prevents pending and future actions from being unable to run
+            self.is_threshold_reachable_without(admin_id, &[permission])?;
+
+            self.rem_permission(admin_id, permission);
+
+            Ok(())
+        }
+
+        fn do_set_approval_threshold(&mut self, threshold: ApprovalsNumber) -> Result<()> {
+            if threshold < 1 || u32::from(threshold) > self.admins_count {
+                return Err(Error::InvalidApprovalThreshold);
+            }
+
+            for permission in Permission::ALL {
+                let holders = self.permission_holders.get(permission).unwrap_or(0);
+                if holders < threshold.into() {
+                    return Err(Error::InvalidApprovalThreshold);
+                }
+            }
+
+            self.approval_threshold = threshold;
+
+            Ok(())
+        }
+
+        fn do_set_code_hash(&mut self, code_hash: Hash) -> Result<()> {
+            self.env()
+                .set_code_hash(&code_hash)
+                .map_err(|_| Error::SetCodeHashFailed)
+        }
+
+        fn run_action(&mut self, action: AdminAction) -> Result<()> {
+            match action {
+                AdminAction::OpenRound {
+                    name,
+                    value,
+                    max_votes,
+                    start_at,
+                    finish_at,
+                    team,
+                    unit,
+                    asset,
+                } => {
+                    let unit = unit.unwrap_or(self.deadline_unit);
+                    let asset = asset.unwrap_or(RewardAsset::Native);
+                    self.do_open_round(
+                        name, value, max_votes, start_at, finish_at, unit, asset, team,
+                    )
+                    .map(|_| ())
+                }
+                AdminAction::CloseRound(round_id) => self.do_close_round(round_id, None),
+                AdminAction::CancelRound(round_id, reason) => {
+                    self.do_cancel_round(round_id, reason)
+                }
+                AdminAction::AmendRound {
+                    round_id,
+                    value,
+                    max_votes,
+                    finish_at,
+                } => self.do_amend_round(round_id, value, max_votes, finish_at),
+                AdminAction::SetRoundSchedule {
+                    name,
+                    period,
+                    duration,
+                    value,
+                    max_votes,
+                    start_at,
+                    asset,
+                } => {
+                    let asset = asset.unwrap_or(RewardAsset::Native);
+                    self.do_set_round_schedule(
+                        name, period, duration, value, max_votes, start_at, asset,
+                    )
+                }
+                AdminAction::RemRoundSchedule => self.do_rem_round_schedule(),
+                AdminAction::AddAdmin(contributor_id) => self.do_add_admin(contributor_id),
+                AdminAction::RemAdmin(contributor_id) => self.do_rem_admin(contributor_id),
+                AdminAction::GrantPermission(admin_id, permission) => {
+                    self.do_grant_permission(admin_id, permission)
+                }
+                AdminAction::RevokePermission(admin_id, permission) => {
+                    self.do_revoke_permission(admin_id, permission)
+                }
+                AdminAction::SetApprovalThreshold(threshold) => {
+                    self.do_set_approval_threshold(threshold)
+                }
+                AdminAction::SetCodeHash(code_hash) => self.do_set_code_hash(code_hash),
+                AdminAction::SetCloseGracePeriod(milliseconds) => {
+                    self.do_set_close_grace_period(milliseconds)
+                }
+                AdminAction::SetCloseGraceBlocks(blocks) => self.do_set_close_grace_blocks(blocks),
+                AdminAction::SetKeeperTipPerMille(tip) => self.do_set_keeper_tip_per_mille(tip),
+                AdminAction::SetClaimPeriod(milliseconds) => self.do_set_claim_period(milliseconds),
+                AdminAction::SetClaimBlocks(blocks) => self.do_set_claim_blocks(blocks),
+                AdminAction::ImportMembers(members) => self.do_import_members(members),
+                AdminAction::SetTeamBudget(team_id, budget) => {
+                    self.do_set_team_budget(team_id, budget)
+                }
+                AdminAction::AddTeamLead(team_id, account_id) => {
+                    self.do_add_team_lead(team_id, account_id)
+                }
+                AdminAction::RemTeamLead(team_id, account_id) => {
+                    self.do_rem_team_lead(team_id, account_id)
+                }
+            }
+        }
+
+        /// Runs the pending action if it has enough approvals, only approvals
+        /// from administrators currently holding the action permission are counted.
+        fn try_run_action(&mut self, action_id: ActionId, pending: PendingAction) -> Result<()> {
+            let permission = pending.action.permission();
+            let approvals = pending
+                .approvals
+                .iter()
+                .filter(|id| self.permissions.contains((**id, permission)))
+                .count();
+
+            if approvals >= self.approval_threshold.into() {
+                self.pending_actions.remove(action_id);
+                self.run_action(pending.action)?;
+                self.env().emit_event(ActionExecuted { action_id });
+            }
+
+            Ok(())
+        }
+
+        // ------------------------------------------------------------------------------
+
+        /// Administrative function: adding a administrator.
+        #[ink(message)]
+        pub fn add_admin(&mut self, contributor_id: AccountId) -> Result<()> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+            self.is_single_approval()?;
+            self.do_add_admin(contributor_id)
+        }
+
+        /// Administrative function: removing a administrator.
+        #[ink(message)]
+        pub fn rem_admin(&mut self, contributor_id: AccountId) -> Result<()> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+            self.is_single_approval()?;
+
+            if self.env().caller() == contributor_id {
+                // this prevents the contract from running out of administrators
+                return Err(Error::CannotRemoveYourself);
+            }
+
+            self.do_rem_admin(contributor_id)
+        }
+
+        /// Administrative function: the caller stops being an administrator,
+        /// the organization cannot run out of administrators.
+        #[ink(message)]
+        pub fn renounce_admin(&mut self) -> Result<()> {
+            let admin_id = self.env().caller();
+
+            if self.members.get(admin_id) != Some(Role::Admin) {
+                return Err(Error::AdministrativeFunction);
+            }
+
+            self.is_admin_removable(admin_id)?;
+
+            self.rem_member(admin_id);
+
+            self.env().emit_event(AdminRenounced { admin_id });
+
+            Ok(())
+        }
+
+        /// Administrative function: proposes to hand over the caller administrator role
+        /// (with its permissions) to `new_admin_id`, who must accept it.
+        #[ink(message)]
+        pub fn propose_admin_transfer(&mut self, new_admin_id: AccountId) -> Result<()> {
+            let admin_id = self.env().caller();
+
+            if self.members.get(admin_id) != Some(Role::Admin) {
+                return Err(Error::AdministrativeFunction);
+            }
+
+            if self.members.contains(new_admin_id) {
+                return Err(Error::MemberAlreadyExists);
+            }
+
+            self.admin_transfers.insert(admin_id, &new_admin_id);
+
+            self.env().emit_event(AdminTransferProposed {
+                from: admin_id,
+                to: new_admin_id,
+            });
+
+            Ok(())
+        }
+
+        /// Accepts the administrator role proposed by `admin_id` to the caller.
+        #[ink(message)]
+        pub fn accept_admin_transfer(&mut self, admin_id: AccountId) -> Result<()> {
+            let new_admin_id = self.env().caller();
+
+            if self.admin_transfers.get(admin_id) != Some(new_admin_id) {
+                return Err(Error::AdminTransferNotExist);
+            }
+
+            if self.members.contains(new_admin_id) {
+                return Err(Error::MemberAlreadyExists);
+            }
+
+            let not_held: Vec<Permission> = Permission::ALL
+                .into_iter()
+                .filter(|permission| !self.permissions.contains((admin_id, *permission)))
+                .collect();
+
+            self.rem_member(admin_id); // also removes the transfer
+            self.add_member(new_admin_id, Role::Admin);
+
+            for permission in not_held {
+                self.rem_permission(new_admin_id, permission);
+            }
+
+            self.env().emit_event(AdminTransferAccepted {
+                from: admin_id,
+                to: new_admin_id,
+            });
+
+            Ok(())
+        }
+
+        /// Get the account to which an administrator proposed to hand over its role.
+        #[ink(message)]
+        pub fn get_admin_transfer(&self, admin_id: AccountId) -> Option<AccountId> {
+            self.admin_transfers.get(admin_id)
+        }
+
+        /// Administrative function: adding a contributor, it takes effect from the next round.
+        #[ink(message)]
+        pub fn add_contributor(&mut self, contributor_id: AccountId) -> Result<()> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+
+            if self.members.contains(contributor_id) {
+                return Err(Error::MemberAlreadyExists);
+            }
+
+            self.add_member(contributor_id, Role::Contributor);
+
+            Ok(())
+        }
+
+        /// Administrative function: imports a batch of members with their role, at most
+        /// `MAX_IMPORT_BATCH_SIZE`, to migrate the members of a previous deployment.
+        /// The accounts already members are skipped, so a batch can be sent again.
+        #[ink(message)]
+        pub fn import_members(&mut self, members: Vec<(AccountId, Role)>) -> Result<()> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+            self.is_single_approval()?;
+            self.do_import_members(members)
+        }
+
+        fn do_import_members(&mut self, members: Vec<(AccountId, Role)>) -> Result<()> {
+            if members.len() > MAX_IMPORT_BATCH_SIZE {
+                return Err(Error::ImportBatchTooLarge);
+            }
+
+            let mut count = 0;
+
+            for (account_id, role) in members {
+                if !self.members.contains(account_id) {
+                    self.add_member(account_id, role);
+                    count += 1;
+                }
+            }
+
+            self.env().emit_event(MembersImported { count });
+
+            Ok(())
+        }
+
+        /// Administrative function: removing a contributor, it takes effect immediately,
+        /// in the open rounds it can no longer vote nor receive votes and it is not paid.
+        #[ink(message)]
+        pub fn rem_contributor(&mut self, contributor_id: AccountId) -> Result<()> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+
+            // administrators are removed with `rem_admin`, which protects the last one
+            self.get_contributor(contributor_id)?;
+
+            self.rem_member(contributor_id);
+
+            Ok(())
+        }
+
+        /// Administrative function: suspending a contributor, it keeps its membership but cannot
+        /// vote, receive votes or be rewarded, it is allowed during an active round.
+        #[ink(message)]
+        pub fn suspend_contributor(&mut self, contributor_id: AccountId) -> Result<()> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+
+            let mut contributor = self.get_contributor(contributor_id)?;
+
+            if contributor.status == MemberStatus::Suspended {
+                return Err(Error::ContributorAlreadySuspended);
+            }
+
+            contributor.status = MemberStatus::Suspended;
+            self.contributors.insert(contributor_id, &contributor);
+
+            self.env().emit_event(ContributorSuspended {
+                contributor_id,
+                admin_id: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Administrative function: reinstating a suspended contributor.
+        #[ink(message)]
+        pub fn reinstate_contributor(&mut self, contributor_id: AccountId) -> Result<()> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+
+            let mut contributor = self.get_contributor(contributor_id)?;
+
+            if contributor.status != MemberStatus::Suspended {
+                return Err(Error::ContributorNotSuspended);
+            }
+
+            contributor.status = MemberStatus::Active;
+            self.contributors.insert(contributor_id, &contributor);
+
+            self.env().emit_event(ContributorReinstated {
+                contributor_id,
+                admin_id: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Get the status of a contributor, `None` if the account is not a contributor.
+        #[ink(message)]
+        pub fn get_member_status(&self, account_id: AccountId) -> Option<MemberStatus> {
+            self.contributors.get(account_id).map(|c| c.status)
+        }
+
+        /// Set the profile of the caller, who must be a member.
+        #[ink(message)]
+        pub fn set_profile(
+            &mut self,
+            display_name: String,
+            handle: String,
+            avatar_uri: String,
+        ) -> Result<()> {
+            let profile = Profile {
+                display_name,
+                handle,
+                avatar_uri,
+            };
+            self.update_profile(self.env().caller(), profile)
+        }
+
+        /// Administrative function: overrides the profile of a member, for moderation.
+        #[ink(message)]
+        pub fn set_profile_of(
+            &mut self,
+            account_id: AccountId,
+            display_name: String,
+            handle: String,
+            avatar_uri: String,
+        ) -> Result<()> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+
+            let profile = Profile {
+                display_name,
+                handle,
+                avatar_uri,
+            };
+            self.update_profile(account_id, profile)
+        }
+
+        /// Get the profile of a member.
+        #[ink(message)]
+        pub fn get_profile(&self, account_id: AccountId) -> Option<Profile> {
+            self.profiles.get(account_id)
+        }
+
+        /// Get the profiles of several members, in the same order,
+        /// at most `MAX_PAGE_SIZE` accounts are consulted.
+        #[ink(message)]
+        pub fn get_profiles(&self, account_ids: Vec<AccountId>) -> Vec<Option<Profile>> {
+            paginate(&account_ids, 0, MAX_PAGE_SIZE)
+                .into_iter()
+                .map(|account_id| self.profiles.get(account_id))
+                .collect()
+        }
+
+        /// Apply to become a contributor, the application must be approved by an administrator.
+        #[ink(message)]
+        pub fn apply_as_contributor(&mut self, motivation: String) -> Result<()> {
+            let applicant_id = self.env().caller();
+
+            if self.members.contains(applicant_id) {
+                return Err(Error::MemberAlreadyExists);
+            }
+
+            if self.applications.contains(applicant_id) {
+                return Err(Error::ApplicationAlreadyExists);
+            }
+
+            if motivation.len() > MAX_MOTIVATION_LENGTH {
+                return Err(Error::MotivationTooLong);
+            }
+
+            let application = Application {
+                motivation: motivation.clone(),
+                applied_at: self.env().block_timestamp(),
+            };
+            self.push_application(applicant_id, &application);
+
+            self.env().emit_event(ApplicationSubmitted {
+                applicant_id,
+                motivation,
+            });
+
+            Ok(())
+        }
+
+        /// Administrative function: approving an application, it takes effect from the next round.
+        #[ink(message)]
+        pub fn approve_application(&mut self, applicant_id: AccountId) -> Result<()> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+
+            if !self.applications.contains(applicant_id) {
+                return Err(Error::ApplicationNotExist);
+            }
+
+            self.add_member(applicant_id, Role::Contributor); // also removes the application
+
+            self.env().emit_event(ApplicationApproved {
+                applicant_id,
+                admin_id: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Administrative function: rejecting an application.
+        #[ink(message)]
+        pub fn reject_application(
+            &mut self,
+            applicant_id: AccountId,
+            reason: String,
+        ) -> Result<()> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+
+            if !self.applications.contains(applicant_id) {
+                return Err(Error::ApplicationNotExist);
+            }
+
+            self.rem_application(applicant_id);
+
+            self.env().emit_event(ApplicationRejected {
+                applicant_id,
+                admin_id: self.env().caller(),
+                reason,
+            });
+
+            Ok(())
+        }
+
+        /// Get the pending application of an account.
+        #[ink(message)]
+        pub fn get_application(&self, applicant_id: AccountId) -> Option<Application> {
+            self.applications.get(applicant_id)
+        }
+
+        /// Get a page of pending applications, at most `MAX_PAGE_SIZE` per page.
+        #[ink(message)]
+        pub fn list_applications(&self, offset: u32, limit: u32) -> Vec<(AccountId, Application)> {
+            page_range(offset, limit, self.applications_count)
+                .map(|index| {
+                    // unwrap is safe here
+                    let id = self.applications_by_index.get(index).unwrap();
+                    (id, self.applications.get(id).unwrap())
+                })
+                .collect()
+        }
+
+        /// Administrative function: adds a new round of distribution,
+        /// it can overlap in time with other active rounds.
+        /// The voting starts at `start_at`, a past date starts it immediately.
+        /// The dates are in `unit`, the organization deadline unit if `None`.
+        /// The funds are in `asset`, the native currency if `None`.
+        #[ink(message)]
+        #[allow(clippy::too_many_arguments)]
+        pub fn open_round(
+            &mut self,
+            name: String,
+            value: Balance,
+            max_votes: VotesNumber,
+            start_at: Timestamp,
+            finish_at: Timestamp,
+            unit: Option<DeadlineUnit>,
+            asset: Option<RewardAsset>,
+        ) -> Result<RoundId> {
+            self.is_caller_allowed(Permission::RoundManager)?;
+            self.is_single_approval()?;
+
+            let unit = unit.unwrap_or(self.deadline_unit);
+            let asset = asset.unwrap_or(RewardAsset::Native);
+            self.do_open_round(
+                name, value, max_votes, start_at, finish_at, unit, asset, None,
+            )
+        }
+
+        /// Team function: adds a new round of distribution only for the members of a team,
+        /// funded by the team budget, the team leads can open it without approvals.
+        /// The team rounds are paid in the native currency, as the team budgets.
+        #[ink(message)]
+        #[allow(clippy::too_many_arguments)]
+        pub fn open_team_round(
+            &mut self,
+            team_id: TeamId,
+            name: String,
+            value: Balance,
+            max_votes: VotesNumber,
+            start_at: Timestamp,
+            finish_at: Timestamp,
+            unit: Option<DeadlineUnit>,
+        ) -> Result<RoundId> {
+            if !self.team_leads.contains((team_id, self.env().caller())) {
+                self.is_caller_allowed(Permission::RoundManager)?;
+                self.is_single_approval()?;
+            }
+
+            let unit = unit.unwrap_or(self.deadline_unit);
+            let asset = RewardAsset::Native;
+            let team = Some(team_id);
+            self.do_open_round(
+                name, value, max_votes, start_at, finish_at, unit, asset, team,
+            )
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn do_open_round(
+            &mut self,
+            name: String,
+            value: Balance,
+            max_votes: VotesNumber,
+            start_at: Timestamp,
+            finish_at: Timestamp,
+            unit: DeadlineUnit,
+            asset: RewardAsset,
+            team: Option<TeamId>,
+        ) -> Result<RoundId> {
+            let mut active_rounds = self.active_rounds.get().unwrap();
+
+            if active_rounds.len() >= MAX_ACTIVE_ROUNDS {
+                return Err(Error::TooManyActiveRounds);
+            }
+
+            if let Some(team_id) = team {
+                // the team budgets are in the native currency, this also keeps the leads
+                // from calling arbitrary contracts as tokens without approvals
+                if asset != RewardAsset::Native {
+                    return Err(Error::RewardAssetMismatch);
+                }
+
+                let mut team = self.find_team(team_id)?;
+
+                if value > team.budget {
+                    return Err(Error::ExceedsTeamBudget(team.budget));
+                }
+
+                // the budget is already reserved, it moves to the round
+                team.budget -= value;
+                self.teams.insert(team_id, &team);
+            } else {
+                // the funds are reserved until the round is closed or cancelled
+                self.reserve_funds(asset, value)?;
+            }
+
+            // with a zero minimum time the range check alone accepts an end before the start
+            if max_votes < 1 || finish_at <= start_at {
+                return Err(Error::InvalidRoundParameter);
+            }
+
+            self.is_round_in_range(unit, start_at, finish_at)?;
+
+            self.last_round_id += 1;
+            let round_id = self.last_round_id;
+
+            let round = Round {
+                name,
+                value,
+                max_votes,
+                start_at,
+                finish_at,
+                unit,
+                asset,
+                status: RoundStatus::Scheduled,
+                team,
+                funded_by_team: if team.is_some() { value } else { 0 },
+                voters_count: 0,
+                total_votes: 0,
+                total_reputation: 0,
+                keeper_tip: 0,
+                settled_count: 0,
+                unclaimed: 0,
+                claims_expire_at: 0,
+                badges_issued: false,
+            };
+            self.rounds.insert(round_id, &round);
+
+            active_rounds.push(round_id);
+            self.active_rounds.set(&active_rounds);
+
+            self.env().emit_event(NewRound {
+                round_id,
+                value,
+                max_votes,
+                start_at,
+                finish_at,
+                unit,
+                team,
+                asset,
+            });
+
+            Ok(round_id)
+        }
+
+        /// Administrative function: distributing funds to contributors of a round,
+        /// the leads of the team of the round can close it without approvals.
+        /// Once the grace period after the end of the round has elapsed anyone can close it,
+        /// receiving the keeper tip from the round funds.
+        /// Each call pays at most `MAX_CLOSE_PAGE_SIZE` voters, once started anyone can
+        /// call it again to pay the next ones until the round is finished.
+        #[ink(message)]
+        pub fn close_round(&mut self, round_id: RoundId) -> Result<()> {
+            let is_closing = self
+                .rounds
+                .get(round_id)
+                .is_some_and(|round| round.status == RoundStatus::Closing);
+
+            // the payouts were fixed when the close started
+            if is_closing {
+                return self.do_close_round(round_id, None);
+            }
+
+            if let Err(error) = self.is_caller_round_lead_or_manager(round_id) {
+                let round = self.rounds.get(round_id).ok_or(Error::RoundNotExist)?;
+                let grace_end = round
+                    .finish_at
+                    .saturating_add(self.close_grace_in(round.unit));
+
+                if self.now_in(round.unit) < grace_end {
+                    return Err(error);
+                }
+
+                return self.do_close_round(round_id, Some(self.env().caller()));
+            }
+            self.do_close_round(round_id, None)
+        }
+
+        /// Administrative function: sends the badges (NFTs) assigned in a closed round
+        /// to the three most reputable contributors.
+        #[ink(message)]
+        pub fn issue_badges(&mut self, round_id: RoundId) -> Result<()> {
+            self.is_caller_allowed(Permission::BadgeIssuer)?;
+
+            let mut round = self.rounds.get(round_id).ok_or(Error::RoundNotExist)?;
+
+            if round.status != RoundStatus::Finished {
+                return Err(Error::NotYetFinishedRound);
+            }
+
+            if round.badges_issued {
+                return Err(Error::BadgesAlreadyIssued);
+            }
+
+            let podium = self.round_podium.get(round_id).unwrap_or_default();
+
+            for (contributor_id, _) in podium {
+                let result = self.round_results.get((round_id, contributor_id)).unwrap();
+
+                if let Some(badge) = result.badge {
+                    self.send_nft(badge, contributor_id)?;
+                }
+            }
+
+            round.badges_issued = true;
+            self.rounds.insert(round_id, &round);
+
+            self.env().emit_event(BadgesIssued { round_id });
+
+            Ok(())
+        }
+
+        /// Transfers to the caller the reward of a closed round, before the claim period expires.
+        #[ink(message)]
+        pub fn claim_reward(&mut self, round_id: RoundId) -> Result<()> {
+            let caller = self.env().caller();
+
+            if !self.rewards.contains((round_id, caller)) {
+                return Err(Error::NoRewardToClaim);
+            }
+
+            if !self.is_round_finished(round_id) {
+                return Err(Error::NotYetFinishedRound);
+            }
+
+            let Some((asset, amount)) = self.take_reward(round_id, caller) else {
+                return Err(Error::ClaimPeriodExpired);
+            };
+
+            let mut pending = self.pending_rewards.get(caller).unwrap_or_default();
+            pending.retain(|id| *id != round_id);
+            self.pending_rewards.insert(caller, &pending);
+
+            self.transfer_asset(asset, caller, amount)
+        }
+
+        /// Transfers to the caller the rewards of all the closed rounds in a single transfer
+        /// per asset, returns the total of each asset, the expired rewards are discarded.
+        #[ink(message)]
+        pub fn claim_all(&mut self) -> Result<Vec<(RewardAsset, Balance)>> {
+            let caller = self.env().caller();
+            let mut totals: Vec<(RewardAsset, Balance)> = Vec::new();
+
+            // the rewards of the rounds still closing are claimed once the close ends
+            let (finished, closing): (Vec<RoundId>, Vec<RoundId>) = self
+                .pending_rewards
+                .take(caller)
+                .unwrap_or_default()
+                .into_iter()
+                .partition(|round_id| self.is_round_finished(*round_id));
+
+            if !closing.is_empty() {
+                self.pending_rewards.insert(caller, &closing);
+            }
+
+            for round_id in finished {
+                let Some((asset, amount)) = self.take_reward(round_id, caller) else {
+                    continue;
+                };
+
+                match totals.iter_mut().find(|(a, _)| *a == asset) {
+                    Some((_, total)) => *total = total.saturating_add(amount),
+                    None => totals.push((asset, amount)),
+                }
+            }
+
+            if totals.is_empty() {
+                return Err(Error::NoRewardToClaim);
+            }
+
+            for (asset, total) in totals.iter() {
+                self.transfer_asset(*asset, caller, *total)?;
+            }
+
+            Ok(totals)
+        }
+
+        /// Returns the unclaimed rewards of a round to the free funds once the claim period
+        /// has expired, anyone can call it.
+        #[ink(message)]
+        pub fn sweep_unclaimed_rewards(&mut self, round_id: RoundId) -> Result<()> {
+            let mut round = self.rounds.get(round_id).ok_or(Error::RoundNotExist)?;
+
+            if round.status != RoundStatus::Finished || round.unclaimed == 0 {
+                return Err(Error::NoRewardToClaim);
+            }
+
+            if !round.is_claim_expired_at(self.now_in(round.unit)) {
+                return Err(Error::ClaimPeriodNotExpired);
+            }
+
+            let amount = round.unclaimed;
+            self.release_funds(round.asset, amount);
+
+            round.unclaimed = 0;
+            self.rounds.insert(round_id, &round);
+
+            self.env()
+                .emit_event(UnclaimedRewardsSwept { round_id, amount });
+
+            Ok(())
+        }
+
+        /// Get the reward not yet claimed of a contributor in a round.
+        #[ink(message)]
+        pub fn get_reward(&self, round_id: RoundId, contributor_id: AccountId) -> Balance {
+            self.rewards.get((round_id, contributor_id)).unwrap_or(0)
+        }
+
+        /// Get the rewards not yet claimed of a contributor, including the expired ones
+        /// until they are discarded.
+        #[ink(message)]
+        pub fn get_pending_rewards(&self, contributor_id: AccountId) -> Vec<(RoundId, Balance)> {
+            self.pending_rewards
+                .get(contributor_id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|round_id| (round_id, self.get_reward(round_id, contributor_id)))
+                .collect()
+        }
+
+        /// Administrative function: registers a recurring schedule of rounds, replacing
+        /// the previous one, the first round can be opened from `start_at`.
+        #[ink(message)]
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_round_schedule(
+            &mut self,
+            name: String,
+            period: Timestamp,
+            duration: Timestamp,
+            value: Balance,
+            max_votes: VotesNumber,
+            start_at: Timestamp,
+            asset: Option<RewardAsset>,
+        ) -> Result<()> {
+            self.is_caller_allowed(Permission::RoundManager)?;
+            self.is_single_approval()?;
+
+            let asset = asset.unwrap_or(RewardAsset::Native);
+            self.do_set_round_schedule(name, period, duration, value, max_votes, start_at, asset)
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn do_set_round_schedule(
+            &mut self,
+            name: String,
+            period: Timestamp,
+            duration: Timestamp,
+            value: Balance,
+            max_votes: VotesNumber,
+            start_at: Timestamp,
+            asset: RewardAsset,
+        ) -> Result<()> {
+            self.is_duration_in_range(self.deadline_unit, duration)?;
+
+            // the rounds of the schedule cannot overlap
+            if max_votes < 1 || duration == 0 || duration > period {
+                return Err(Error::InvalidRoundParameter);
+            }
+
+            self.round_schedule = Some(RoundSchedule {
+                name,
+                period,
+                duration,
+                value,
+                max_votes,
+                asset,
+                next_start_at: start_at,
+                last_round_id: None,
+                rounds_count: 0,
+            });
+
+            Ok(())
+        }
+
+        /// Administrative function: removes the recurring schedule of rounds,
+        /// the rounds already opened are not affected.
+        #[ink(message)]
+        pub fn rem_round_schedule(&mut self) -> Result<()> {
+            self.is_caller_allowed(Permission::RoundManager)?;
+            self.is_single_approval()?;
+            self.do_rem_round_schedule()
+        }
+
+        fn do_rem_round_schedule(&mut self) -> Result<()> {
+            if self.round_schedule.take().is_none() {
+                return Err(Error::RoundScheduleNotExist);
+            }
+            Ok(())
+        }
+
+        /// Opens the next round of the recurring schedule, anyone can call it once
+        /// the previous round of the schedule is closed and the period has elapsed.
+        #[ink(message)]
+        pub fn open_next_scheduled_round(&mut self) -> Result<RoundId> {
+            let mut schedule = self
+                .round_schedule
+                .clone()
+                .ok_or(Error::RoundScheduleNotExist)?;
+
+            let unit = self.deadline_unit;
+            let now = self.now_in(unit);
+
+            if now < schedule.next_start_at {
+                return Err(Error::ScheduledRoundNotDue);
+            }
+
+            if let Some(round_id) = schedule.last_round_id {
+                match self.rounds.get(round_id).map(|round| round.status) {
+                    Some(RoundStatus::Finished) | Some(RoundStatus::Cancelled) => {}
+                    _ => return Err(Error::PreviousRoundNotClosed),
+                }
+            }
+
+            let name = format!("{} #{}", schedule.name, schedule.rounds_count + 1);
+            let finish_at = now.saturating_add(schedule.duration);
+
+            // the funding checks of the round apply
+            let round_id = self.do_open_round(
+                name,
+                schedule.value,
+                schedule.max_votes,
+                now,
+                finish_at,
+                unit,
+                schedule.asset,
+                None,
+            )?;
+
+            schedule.next_start_at = now.saturating_add(schedule.period);
+            schedule.last_round_id = Some(round_id);
+            schedule.rounds_count += 1;
+            self.round_schedule = Some(schedule);
+
+            Ok(round_id)
+        }
+
+        /// Get the recurring schedule of rounds.
+        #[ink(message)]
+        pub fn get_round_schedule(&self) -> Option<RoundSchedule> {
+            self.round_schedule.clone()
+        }
+
+        /// Administrative function: extends the deadline, raises the votes limit or tops up
+        /// the funds of a round not yet finished, the values cannot be lowered so that the
+        /// votes already cast remain valid, the leads of the team of the round can amend it
+        /// without approvals.
+        #[ink(message)]
+        pub fn amend_round(
+            &mut self,
+            round_id: RoundId,
+            value: Balance,
+            max_votes: VotesNumber,
+            finish_at: Timestamp,
+        ) -> Result<()> {
+            self.is_caller_round_lead_or_manager(round_id)?;
+            self.do_amend_round(round_id, value, max_votes, finish_at)
+        }
+
+        fn do_amend_round(
+            &mut self,
+            round_id: RoundId,
+            value: Balance,
+            max_votes: VotesNumber,
+            finish_at: Timestamp,
+        ) -> Result<()> {
+            let mut round = self.rounds.get(round_id).ok_or(Error::RoundNotExist)?;
+            let now = self.now_in(round.unit);
+
+            match round.status_at(now) {
+                RoundStatus::Scheduled | RoundStatus::Voting => {}
+                RoundStatus::AwaitingClose => return Err(Error::RoundVotingEnded),
+                RoundStatus::Closing | RoundStatus::Finished | RoundStatus::Cancelled => {
+                    return Err(Error::IsNoActiveRound)
+                }
+            }
+
+            if value < round.value || max_votes < round.max_votes || finish_at < round.finish_at {
+                return Err(Error::CannotLowerRoundParameter);
+            }
+
+            self.is_round_in_range(round.unit, round.start_at, finish_at)?;
+
+            let top_up = value - round.value;
+
+            if let Some(team_id) = round.team {
+                let mut team = self.find_team(team_id)?;
+
+                if top_up > team.budget {
+                    return Err(Error::ExceedsTeamBudget(team.budget));
+                }
+
+                // the budget is already reserved, it moves to the round
+                team.budget -= top_up;
+                self.teams.insert(team_id, &team);
+
+                round.funded_by_team += top_up; // at most the value of the round
+            } else {
+                self.reserve_funds(round.asset, top_up)?;
+            }
+
+            self.env().emit_event(RoundAmended {
+                round_id,
+                old_value: round.value,
+                value,
+                old_max_votes: round.max_votes,
+                max_votes,
+                old_finish_at: round.finish_at,
+                finish_at,
+                unit: round.unit,
+            });
+
+            round.value = value;
+            round.max_votes = max_votes;
+            round.finish_at = finish_at;
+            self.rounds.insert(round_id, &round);
+
+            Ok(())
+        }
+
+        /// Administrative function: discards a round without distributing its funds,
+        /// the votes are voided and the team budget is refunded,
+        /// the leads of the team of the round can cancel it without approvals.
+        #[ink(message)]
+        pub fn cancel_round(&mut self, round_id: RoundId, reason: String) -> Result<()> {
+            self.is_caller_round_lead_or_manager(round_id)?;
+            self.do_cancel_round(round_id, reason)
+        }
+
+        fn do_cancel_round(&mut self, round_id: RoundId, reason: String) -> Result<()> {
+            let mut round = self.rounds.get(round_id).ok_or(Error::RoundNotExist)?;
+
+            // a round partially paid cannot be cancelled
+            if let RoundStatus::Closing | RoundStatus::Finished | RoundStatus::Cancelled =
+                round.status
+            {
+                return Err(Error::IsNoActiveRound);
+            }
+
+            // the participations belong to the round, so the votes are voided
+            // without visiting the voters
+
+            // the part taken from the team budget remains reserved for the team,
+            // the rest of the funds (donations) become free
+            let mut released = round.value;
+
+            if let Some((team_id, mut team)) = round
+                .team
+                .and_then(|team_id| Some((team_id, self.teams.get(team_id)?)))
+            {
+                team.budget = team.budget.saturating_add(round.funded_by_team);
+                self.teams.insert(team_id, &team);
+
+                released -= round.funded_by_team;
+            }
+
+            self.release_funds(round.asset, released);
+
+            round.status = RoundStatus::Cancelled;
+            self.rounds.insert(round_id, &round);
+
+            self.rem_active_round(round_id);
+
+            self.env().emit_event(RoundCancelled { round_id, reason });
+
+            Ok(())
+        }
+
+        fn do_close_round(&mut self, round_id: RoundId, keeper: Option<AccountId>) -> Result<()> {
+            let mut round = self.rounds.get(round_id).ok_or(Error::RoundNotExist)?;
+
+            match round.status_at(self.now_in(round.unit)) {
+                RoundStatus::AwaitingClose => self.start_close(round_id, &mut round, keeper)?,
+                RoundStatus::Closing => {}
+                RoundStatus::Scheduled | RoundStatus::Voting => {
+                    return Err(Error::NotYetFinishedRound)
+                }
+                RoundStatus::Finished | RoundStatus::Cancelled => {
+                    return Err(Error::IsNoActiveRound)
+                }
+            }
+
+            let value = round.value - round.keeper_tip; // the tip is at most the value
+            let end = round
+                .settled_count
+                .saturating_add(MAX_CLOSE_PAGE_SIZE)
+                .min(round.voters_count);
+            let mut podium = self.round_podium.get(round_id).unwrap_or_default();
+
+            for index in round.settled_count..end {
+                let contributor_id = self.round_voters.get((round_id, index)).unwrap();
+                let contributor = self.contributors.get(contributor_id);
+
+                // Removed and suspended contributors (and removed team members) are not paid
+                // nor ranked, their share is released with the rest of the funds
+                if !contributor.is_some_and(|c| c.status == MemberStatus::Active)
+                    || !self.is_round_member(&round, round_id, contributor_id)
+                {
+                    continue;
+                }
+
+                let participation = self.get_participation(round_id, contributor_id);
+                let reputation = participation.reputation;
+
+                // the shares of all the voters add up at most to the value
+                let amount = share(value, reputation, round.total_reputation);
+
+                if amount > 0 {
+                    self.rewards.insert((round_id, contributor_id), &amount);
+                    self.push_pending_reward(contributor_id, round_id);
+
+                    round.unclaimed = round.unclaimed.saturating_add(amount);
+                }
+
+                let result = RoundResult {
+                    reputation,
+                    payout: amount,
+                    votes_cast: participation.votes_submitted,
+                    votes_received: participation.votes_received,
+                    badge: None,
+                };
+                self.push_round_result(round_id, contributor_id, &result);
+
+                rank(&mut podium, contributor_id, reputation, Badge::ALL.len());
+            }
+
+            round.settled_count = end;
+            self.round_podium.insert(round_id, &podium);
+
+            if round.settled_count < round.voters_count {
+                self.rounds.insert(round_id, &round);
+                return Ok(());
+            }
+
+            self.finish_close(round_id, round, podium);
+
+            Ok(())
+        }
+
+        /// Starts the close of a round, paying the tip to the keeper if any,
+        /// from then on the payouts are fixed and the round cannot be cancelled.
+        fn start_close(
+            &mut self,
+            round_id: RoundId,
+            round: &mut Round,
+            keeper: Option<AccountId>,
+        ) -> Result<()> {
+            if let Some(keeper) = keeper {
+                let tip = per_mille(round.value, self.keeper_tip_per_mille);
+
+                if tip > 0 {
+                    self.transfer_asset(round.asset, keeper, tip)?;
+                }
+
+                // at most `MAX_KEEPER_TIP_PER_MILLE` of the value
+                round.keeper_tip = tip;
+
+                self.env().emit_event(RoundClosedByKeeper {
+                    round_id,
+                    keeper,
+                    tip,
+                });
+            }
+
+            round.status = RoundStatus::Closing;
+
+            Ok(())
+        }
+
+        /// Finishes the close of a round once all the voters were paid.
+        fn finish_close(&mut self, round_id: RoundId, mut round: Round, podium: Podium) {
+            // The most reputable contributors get the badges, with fewer contributors
+            // the lowest badges are not assigned, they are sent with `issue_badges`
+            for (badge, (contributor_id, _)) in Badge::ALL.into_iter().zip(podium.iter()) {
+                let mut result = self.round_results.get((round_id, *contributor_id)).unwrap();
+                result.badge = Some(badge);
+                self.round_results
+                    .insert((round_id, *contributor_id), &result);
+            }
+
+            if podium.is_empty() {
+                // nobody to reward, the funds are released below
+                self.env().emit_event(RoundWithoutRecipients {
+                    round_id,
+                    value: round.value - round.keeper_tip,
+                });
+            }
+
+            // the rewards remain reserved until they are claimed, the tip already left
+            // the balance and the rest of the value (shares of removed contributors
+            // and the remainders of the division) becomes free
+            self.release_funds(round.asset, round.value - round.unclaimed);
+
+            round.claims_expire_at = self
+                .now_in(round.unit)
+                .saturating_add(self.claim_period_in(round.unit));
+            round.status = RoundStatus::Finished;
+            self.rounds.insert(round_id, &round);
+
+            self.rem_active_round(round_id);
+
+            self.env().emit_event(CloseRound {
+                round_id,
+                total_votes: round.total_votes,
+                total_reputation: round.total_reputation,
+            });
+        }
+
+        /// Administrative function: grants a permission to an administrator.
+        #[ink(message)]
+        pub fn grant_permission(
+            &mut self,
+            admin_id: AccountId,
+            permission: Permission,
+        ) -> Result<()> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+            self.is_single_approval()?;
+            self.do_grant_permission(admin_id, permission)
+        }
+
+        /// Administrative function: revokes a permission from an administrator.
+        #[ink(message)]
+        pub fn revoke_permission(
+            &mut self,
+            admin_id: AccountId,
+            permission: Permission,
+        ) -> Result<()> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+            self.is_single_approval()?;
+
+            if self.env().caller() == admin_id {
+                // this prevents the organization from running out of permission holders
+                return Err(Error::CannotRevokeYourself);
+            }
+
+            self.do_revoke_permission(admin_id, permission)
+        }
+
+        /// Check if an account holds a permission.
+        #[ink(message)]
+        pub fn has_permission(&self, account_id: AccountId, permission: Permission) -> bool {
+            self.permissions.contains((account_id, permission))
+        }
+
+        /// Administrative function: creates a team, without members nor budget.
+        #[ink(message)]
+        pub fn create_team(&mut self, name: String) -> Result<TeamId> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+
+            if name.len() > MAX_TEAM_NAME_LENGTH {
+                return Err(Error::TeamNameTooLong);
+            }
+
+            self.last_team_id += 1;
+            let team_id = self.last_team_id;
+
+            let team = Team {
+                name: name.clone(),
+                budget: 0,
+                members_count: 0,
+                leads_count: 0,
+            };
+            self.teams.insert(team_id, &team);
+
+            self.env().emit_event(TeamCreated { team_id, name });
+
+            Ok(team_id)
+        }
+
+        /// Administrative function: deletes a team, it must have no members nor leads
+        /// and its rounds must be finished.
+        #[ink(message)]
+        pub fn delete_team(&mut self, team_id: TeamId) -> Result<()> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+            let team = self.find_team(team_id)?;
+
+            if team.members_count > 0 || team.leads_count > 0 {
+                return Err(Error::TeamNotEmpty);
+            }
+
+            for round_id in self.active_rounds.get().unwrap() {
+                if self.rounds.get(round_id).and_then(|round| round.team) == Some(team_id) {
+                    return Err(Error::TeamHasActiveRound);
+                }
+            }
+
+            self.teams.remove(team_id);
+            self.release_funds(RewardAsset::Native, team.budget);
+
+            self.env().emit_event(TeamDeleted { team_id });
 
             Ok(())
         }
 
-        /// Administrative function: adding a contributor, there must be no active round.
+        /// Administrative function: set the funds available for the team rounds,
+        /// the budget is reserved from the free funds of the treasury.
         #[ink(message)]
-        pub fn add_contributor(&mut self, contributor_id: AccountId) -> Result<()> {
-            self.is_caller_admin()?;
-            self.is_active_round()?;
+        pub fn set_team_budget(&mut self, team_id: TeamId, budget: Balance) -> Result<()> {
+            self.is_caller_allowed(Permission::Treasurer)?;
+            self.is_single_approval()?;
+            self.do_set_team_budget(team_id, budget)
+        }
 
-            if self.members.contains(contributor_id) {
-                return Err(Error::MemberAlreadyExists);
+        fn do_set_team_budget(&mut self, team_id: TeamId, budget: Balance) -> Result<()> {
+            let mut team = self.find_team(team_id)?;
+
+            if budget > team.budget {
+                self.reserve_funds(RewardAsset::Native, budget - team.budget)?;
+            } else {
+                self.release_funds(RewardAsset::Native, team.budget - budget);
             }
 
-            self.add_member(contributor_id, Role::Contributor);
+            team.budget = budget;
+            self.teams.insert(team_id, &team);
 
             Ok(())
         }
 
-        /// Administrative function: removing a contributor, there must be no active round.
+        /// Administrative function: adds a lead to a team, who must be a member,
+        /// the leads open, amend, cancel and close the team rounds without approvals.
         #[ink(message)]
-        pub fn rem_contributor(&mut self, contributor_id: AccountId) -> Result<()> {
-            self.is_caller_admin()?;
-            self.is_active_round()?;
+        pub fn add_team_lead(&mut self, team_id: TeamId, account_id: AccountId) -> Result<()> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+            self.is_single_approval()?;
+            self.do_add_team_lead(team_id, account_id)
+        }
 
-            if !self.members.contains(contributor_id) {
+        fn do_add_team_lead(&mut self, team_id: TeamId, account_id: AccountId) -> Result<()> {
+            let mut team = self.find_team(team_id)?;
+
+            if !self.members.contains(account_id) {
                 return Err(Error::MemberNotExist);
             }
 
-            self.rem_member(contributor_id);
+            if self.team_leads.contains((team_id, account_id)) {
+                return Err(Error::TeamLeadAlreadyExists);
+            }
+
+            self.push_member_team(account_id, team_id)?;
+            self.team_leads.insert((team_id, account_id), &());
+
+            team.leads_count += 1;
+            self.teams.insert(team_id, &team);
 
             Ok(())
         }
 
-        /// Administrative function: adds a new round of distribution.
+        /// Administrative function: removes a lead from a team.
         #[ink(message)]
-        pub fn open_round(
+        pub fn rem_team_lead(&mut self, team_id: TeamId, account_id: AccountId) -> Result<()> {
+            self.is_caller_allowed(Permission::MembershipManager)?;
+            self.is_single_approval()?;
+            self.do_rem_team_lead(team_id, account_id)
+        }
+
+        fn do_rem_team_lead(&mut self, team_id: TeamId, account_id: AccountId) -> Result<()> {
+            let mut team = self.find_team(team_id)?;
+
+            if !self.team_leads.contains((team_id, account_id)) {
+                return Err(Error::TeamLeadNotExist);
+            }
+
+            self.team_leads.remove((team_id, account_id));
+            self.rem_member_team(account_id, team_id);
+
+            team.leads_count -= 1;
+            self.teams.insert(team_id, &team);
+
+            Ok(())
+        }
+
+        /// Team function: adds a contributor to a team, it takes effect from the next round.
+        #[ink(message)]
+        pub fn add_team_member(
             &mut self,
-            name: String,
-            value: Balance,
-            max_votes: VotesNumber,
-            finish_at: Timestamp,
+            team_id: TeamId,
+            contributor_id: AccountId,
         ) -> Result<()> {
-            self.is_caller_admin()?;
-            self.is_active_round()?;
+            self.is_caller_team_lead_or_allowed(team_id, Permission::MembershipManager)?;
+
+            let mut team = self.find_team(team_id)?;
+            self.get_contributor(contributor_id)?;
 
-            if value <= self.env().balance() + self.env().minimum_balance() {
-                return Err(Error::InsufficientFunds);
+            if self.team_members.contains((team_id, contributor_id)) {
+                return Err(Error::TeamMemberAlreadyExists);
             }
 
-            if max_votes < 1 {
-                return Err(Error::InvalidRoundParameter);
+            self.push_member_team(contributor_id, team_id)?;
+            self.team_members
+                .insert((team_id, contributor_id), &self.last_round_id);
+
+            team.members_count += 1;
+            self.teams.insert(team_id, &team);
+
+            Ok(())
+        }
+
+        /// Team function: removes a contributor from a team, it takes effect immediately,
+        /// in the open rounds of the team it can no longer vote nor receive votes
+        /// and it is not paid.
+        #[ink(message)]
+        pub fn rem_team_member(
+            &mut self,
+            team_id: TeamId,
+            contributor_id: AccountId,
+        ) -> Result<()> {
+            self.is_caller_team_lead_or_allowed(team_id, Permission::MembershipManager)?;
+
+            let mut team = self.find_team(team_id)?;
+
+            if !self.team_members.contains((team_id, contributor_id)) {
+                return Err(Error::TeamMemberNotExist);
             }
 
-            if finish_at < self.env().block_timestamp() + self.min_elapsed_milliseconds {
-                return Err(Error::InvalidRoundParameter);
+            self.team_members.remove((team_id, contributor_id));
+            self.rem_member_team(contributor_id, team_id);
+
+            team.members_count -= 1;
+            self.teams.insert(team_id, &team);
+
+            Ok(())
+        }
+
+        /// Get a team.
+        #[ink(message)]
+        pub fn get_team(&self, team_id: TeamId) -> Option<Team> {
+            self.teams.get(team_id)
+        }
+
+        /// Get the last created team id.
+        #[ink(message)]
+        pub fn get_last_team_id(&self) -> TeamId {
+            self.last_team_id
+        }
+
+        /// Check if an account is a member of a team.
+        #[ink(message)]
+        pub fn is_team_member(&self, team_id: TeamId, account_id: AccountId) -> bool {
+            self.teams.contains(team_id) && self.team_members.contains((team_id, account_id))
+        }
+
+        /// Check if an account is a lead of a team.
+        #[ink(message)]
+        pub fn is_team_lead(&self, team_id: TeamId, account_id: AccountId) -> bool {
+            self.teams.contains(team_id) && self.team_leads.contains((team_id, account_id))
+        }
+
+        /// Administrative function: set the number of administrators approvals needed
+        /// to run an administrative action, must be between 1 and the number of administrators.
+        #[ink(message)]
+        pub fn set_approval_threshold(&mut self, threshold: ApprovalsNumber) -> Result<()> {
+            self.is_caller_allowed(Permission::ParameterManager)?;
+            self.is_single_approval()?;
+            self.do_set_approval_threshold(threshold)
+        }
+
+        /// Administrative function: proposes an administrative action, which runs as soon as
+        /// it is approved by `approval_threshold` administrators (the proposer included).
+        #[ink(message)]
+        pub fn propose_action(
+            &mut self,
+            action: AdminAction,
+            expires_at: Timestamp,
+        ) -> Result<ActionId> {
+            self.is_caller_allowed(action.permission())?;
+
+            let proposer = self.env().caller();
+
+            if expires_at <= self.env().block_timestamp() {
+                return Err(Error::InvalidActionParameter);
             }
 
-            let round = Round {
-                name,
-                value,
-                max_votes,
-                finish_at,
-                is_finished: false,
+            if action == AdminAction::RemAdmin(proposer) {
+                // this prevents the contract from running out of administrators
+                return Err(Error::CannotRemoveYourself);
+            }
+
+            if let AdminAction::RevokePermission(admin_id, _) = action {
+                if admin_id == proposer {
+                    // this prevents the organization from running out of permission holders
+                    return Err(Error::CannotRevokeYourself);
+                }
+            }
+
+            self.last_action_id += 1;
+            let action_id = self.last_action_id;
+
+            let pending = PendingAction {
+                action,
+                proposer,
+                approvals: Vec::from([proposer]),
+                expires_at,
             };
-            self.current_round_id += 1;
-            self.rounds.insert(self.current_round_id, &round);
+            self.pending_actions.insert(action_id, &pending);
 
-            self.env().emit_event(NewRound {
-                round_id: self.current_round_id,
-                value,
-                max_votes,
-                finish_at,
+            self.env().emit_event(ActionProposed {
+                action_id,
+                proposer,
+                expires_at,
+            });
+
+            self.try_run_action(action_id, pending)?;
+
+            Ok(action_id)
+        }
+
+        /// Administrative function: approves a pending administrative action,
+        /// runs it if the approval threshold is reached.
+        #[ink(message)]
+        pub fn approve_action(&mut self, action_id: ActionId) -> Result<()> {
+            let approver = self.env().caller();
+            let mut pending = self
+                .pending_actions
+                .get(action_id)
+                .ok_or(Error::ActionNotExist)?;
+
+            self.is_caller_allowed(pending.action.permission())?;
+
+            if pending.expires_at <= self.env().block_timestamp() {
+                return Err(Error::ActionExpired);
+            }
+
+            if pending.approvals.contains(&approver) {
+                return Err(Error::ActionAlreadyApproved);
+            }
+
+            pending.approvals.push(approver);
+            self.pending_actions.insert(action_id, &pending);
+
+            self.env().emit_event(ActionApproved {
+                action_id,
+                approver,
+                approvals: pending.approvals.len() as ApprovalsNumber, // bounded by threshold
             });
 
+            self.try_run_action(action_id, pending)
+        }
+
+        /// Discards an expired pending administrative action, anyone can call it.
+        #[ink(message)]
+        pub fn expire_action(&mut self, action_id: ActionId) -> Result<()> {
+            let pending = self
+                .pending_actions
+                .get(action_id)
+                .ok_or(Error::ActionNotExist)?;
+
+            if pending.expires_at > self.env().block_timestamp() {
+                return Err(Error::ActionNotYetExpired);
+            }
+
+            self.pending_actions.remove(action_id);
+
+            self.env().emit_event(ActionExpired { action_id });
+
+            Ok(())
+        }
+
+        /// Get a pending administrative action.
+        #[ink(message)]
+        pub fn get_pending_action(&self, action_id: ActionId) -> Option<PendingAction> {
+            self.pending_actions.get(action_id)
+        }
+
+        /// Get the last proposed administrative action id.
+        #[ink(message)]
+        pub fn get_last_action_id(&self) -> ActionId {
+            self.last_action_id
+        }
+
+        /// Get the number of administrators approvals needed to run an administrative action.
+        #[ink(message)]
+        pub fn get_approval_threshold(&self) -> ApprovalsNumber {
+            self.approval_threshold
+        }
+
+        /// Administrative function: set the minimum time for a round, for the next round.
+        #[ink(message)]
+        pub fn set_min_elapsed_milliseconds(&mut self, milliseconds: Timestamp) -> Result<()> {
+            self.is_caller_allowed(Permission::ParameterManager)?;
+
+            if milliseconds > self.max_elapsed_milliseconds {
+                return Err(Error::InvalidDurationRange);
+            }
+
+            let old_value = self.min_elapsed_milliseconds;
+            self.min_elapsed_milliseconds = milliseconds;
+
+            self.emit_params_changed(Parameter::MinElapsedMilliseconds, old_value, milliseconds);
+            Ok(())
+        }
+
+        /// Administrative function: set the maximum time for a round, for the next round.
+        #[ink(message)]
+        pub fn set_max_elapsed_milliseconds(&mut self, milliseconds: Timestamp) -> Result<()> {
+            self.is_caller_allowed(Permission::ParameterManager)?;
+
+            if milliseconds < self.min_elapsed_milliseconds {
+                return Err(Error::InvalidDurationRange);
+            }
+
+            let old_value = self.max_elapsed_milliseconds;
+            self.max_elapsed_milliseconds = milliseconds;
+
+            self.emit_params_changed(Parameter::MaxElapsedMilliseconds, old_value, milliseconds);
+            Ok(())
+        }
+
+        /// Get the role of a member, `None` if the account is not a member.
+        #[ink(message)]
+        pub fn get_role(&self, account_id: AccountId) -> Option<Role> {
+            self.members.get(account_id)
+        }
+
+        /// Get the number of members (administrators and contributors).
+        #[ink(message)]
+        pub fn member_count(&self) -> u32 {
+            self.admins_count + self.contributor_count()
+        }
+
+        /// Get the number of contributors.
+        #[ink(message)]
+        pub fn contributor_count(&self) -> u32 {
+            self.contributors_count
+        }
+
+        /// Get a page of contributors, at most `MAX_PAGE_SIZE` per page.
+        #[ink(message)]
+        pub fn list_contributors(&self, offset: u32, limit: u32) -> Vec<AccountId> {
+            self.contributors_page(offset, limit)
+        }
+
+        /// Get a page of administrators, at most `MAX_PAGE_SIZE` per page.
+        #[ink(message)]
+        pub fn list_admins(&self, offset: u32, limit: u32) -> Vec<AccountId> {
+            paginate(&self.admins_list.get().unwrap(), offset, limit)
+        }
+
+        /// Administrative function: upgrades the contract code, the storage is kept,
+        /// so the new code must have the same storage layout.
+        #[ink(message)]
+        pub fn set_code_hash(&mut self, code_hash: Hash) -> Result<()> {
+            self.is_caller_allowed(Permission::ParameterManager)?;
+            self.is_single_approval()?;
+            self.do_set_code_hash(code_hash)
+        }
+
+        /// Get a round with its current state.
+        #[ink(message)]
+        pub fn get_round(&self, round_id: RoundId) -> Option<RoundInfo> {
+            self.get_round_info(round_id)
+        }
+
+        /// Get the last opened round among the active ones, `None` if there is no active round.
+        #[ink(message)]
+        pub fn get_current_round(&self) -> Option<RoundInfo> {
+            let round_id = self.active_rounds.get().unwrap().last().copied()?;
+            self.get_round_info(round_id)
+        }
+
+        /// Get the number of created rounds, which is also the last round id.
+        #[ink(message)]
+        pub fn round_count(&self) -> u32 {
+            self.last_round_id
+        }
+
+        /// Get a page of rounds, from the oldest or from the newest,
+        /// at most `MAX_PAGE_SIZE` per page.
+        #[ink(message)]
+        pub fn list_rounds(&self, offset: u32, limit: u32, newest_first: bool) -> Vec<RoundInfo> {
+            page_range(offset, limit, self.last_round_id)
+                .map(|index| {
+                    if newest_first {
+                        self.last_round_id - index
+                    } else {
+                        index + 1
+                    }
+                })
+                .filter_map(|round_id| self.get_round_info(round_id)) // rounds are never removed
+                .collect()
+        }
+
+        /// Get the final result of a contributor in a closed round,
+        /// `None` if the contributor was not paid in the round.
+        #[ink(message)]
+        pub fn get_round_result(
+            &self,
+            round_id: RoundId,
+            account_id: AccountId,
+        ) -> Option<RoundResult> {
+            self.round_results.get((round_id, account_id))
+        }
+
+        /// Get a page of the final results of a closed round, in the order they were paid,
+        /// at most `MAX_PAGE_SIZE` per page.
+        #[ink(message)]
+        pub fn get_round_results(
+            &self,
+            round_id: RoundId,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<(AccountId, RoundResult)> {
+            let count = self.round_results_count.get(round_id).unwrap_or(0);
+
+            page_range(offset, limit, count)
+                .map(|index| {
+                    // unwraps are safe here
+                    let account_id = self.round_results_by_index.get((round_id, index)).unwrap();
+                    let result = self.round_results.get((round_id, account_id)).unwrap();
+                    (account_id, result)
+                })
+                .collect()
+        }
+
+        /// Get the current state of a round, `None` if the round does not exist.
+        #[ink(message)]
+        pub fn get_round_status(&self, round_id: RoundId) -> Option<RoundStatus> {
+            self.rounds
+                .get(round_id)
+                .map(|round| round.status_at(self.now_in(round.unit)))
+        }
+
+        /// Get the rounds not yet closed.
+        #[ink(message)]
+        pub fn get_active_rounds(&self) -> Vec<RoundId> {
+            self.active_rounds.get().unwrap()
+        }
+
+        /// Administrative function: set the time after the end of a round
+        /// from which anyone can close it, at least `MIN_CLOSE_GRACE_PERIOD`.
+        #[ink(message)]
+        pub fn set_close_grace_period(&mut self, milliseconds: Timestamp) -> Result<()> {
+            self.is_caller_allowed(Permission::ParameterManager)?;
+            self.is_single_approval()?;
+            self.do_set_close_grace_period(milliseconds)
+        }
+
+        fn do_set_close_grace_period(&mut self, milliseconds: Timestamp) -> Result<()> {
+            // a shorter grace would let anyone close the rounds as keeper right at the end
+            if milliseconds < MIN_CLOSE_GRACE_PERIOD {
+                return Err(Error::InvalidCloseGrace);
+            }
+
+            let old_value = self.close_grace_period;
+            self.close_grace_period = milliseconds;
+
+            self.emit_params_changed(Parameter::CloseGracePeriod, old_value, milliseconds);
             Ok(())
         }
 
-        /// Administrative function: distributing funds to contributors.
+        /// Administrative function: set the tip for whoever closes a round after
+        /// the grace period, in parts per thousand of the round funds.
+        #[ink(message)]
+        pub fn set_keeper_tip_per_mille(&mut self, tip: u16) -> Result<()> {
+            self.is_caller_allowed(Permission::ParameterManager)?;
+            self.is_single_approval()?;
+            self.do_set_keeper_tip_per_mille(tip)
+        }
+
+        fn do_set_keeper_tip_per_mille(&mut self, tip: u16) -> Result<()> {
+            if tip > MAX_KEEPER_TIP_PER_MILLE {
+                return Err(Error::InvalidKeeperTip);
+            }
+
+            let old_value = self.keeper_tip_per_mille;
+            self.keeper_tip_per_mille = tip;
+
+            self.emit_params_changed(Parameter::KeeperTipPerMille, old_value.into(), tip.into());
+            Ok(())
+        }
+
+        /// Administrative function: set the minimum time for a round with block number
+        /// deadlines, in blocks, for the next round.
+        #[ink(message)]
+        pub fn set_min_elapsed_blocks(&mut self, blocks: BlockNumber) -> Result<()> {
+            self.is_caller_allowed(Permission::ParameterManager)?;
+
+            if blocks > self.max_elapsed_blocks {
+                return Err(Error::InvalidDurationRange);
+            }
+
+            let old_value = self.min_elapsed_blocks;
+            self.min_elapsed_blocks = blocks;
+
+            self.emit_params_changed(Parameter::MinElapsedBlocks, old_value.into(), blocks.into());
+            Ok(())
+        }
+
+        /// Administrative function: set the maximum time for a round with block number
+        /// deadlines, in blocks, for the next round.
+        #[ink(message)]
+        pub fn set_max_elapsed_blocks(&mut self, blocks: BlockNumber) -> Result<()> {
+            self.is_caller_allowed(Permission::ParameterManager)?;
+
+            if blocks < self.min_elapsed_blocks {
+                return Err(Error::InvalidDurationRange);
+            }
+
+            let old_value = self.max_elapsed_blocks;
+            self.max_elapsed_blocks = blocks;
+
+            self.emit_params_changed(Parameter::MaxElapsedBlocks, old_value.into(), blocks.into());
+            Ok(())
+        }
+
+        /// Administrative function: set the blocks after the end of a round
+        /// (with block number deadlines) from which anyone can close it,
+        /// at least `MIN_CLOSE_GRACE_BLOCKS`.
+        #[ink(message)]
+        pub fn set_close_grace_blocks(&mut self, blocks: BlockNumber) -> Result<()> {
+            self.is_caller_allowed(Permission::ParameterManager)?;
+            self.is_single_approval()?;
+            self.do_set_close_grace_blocks(blocks)
+        }
+
+        fn do_set_close_grace_blocks(&mut self, blocks: BlockNumber) -> Result<()> {
+            if blocks < MIN_CLOSE_GRACE_BLOCKS {
+                return Err(Error::InvalidCloseGrace);
+            }
+
+            let old_value = self.close_grace_blocks;
+            self.close_grace_blocks = blocks;
+
+            self.emit_params_changed(Parameter::CloseGraceBlocks, old_value.into(), blocks.into());
+            Ok(())
+        }
+
+        /// Administrative function: set the time after the close of a round to claim
+        /// the rewards, for the next closed rounds, at least `MIN_CLAIM_PERIOD`.
+        #[ink(message)]
+        pub fn set_claim_period(&mut self, milliseconds: Timestamp) -> Result<()> {
+            self.is_caller_allowed(Permission::ParameterManager)?;
+            self.is_single_approval()?;
+            self.do_set_claim_period(milliseconds)
+        }
+
+        fn do_set_claim_period(&mut self, milliseconds: Timestamp) -> Result<()> {
+            // a shorter period could void the rewards before they can be claimed
+            if milliseconds < MIN_CLAIM_PERIOD {
+                return Err(Error::InvalidClaimPeriod);
+            }
+
+            let old_value = self.claim_period;
+            self.claim_period = milliseconds;
+
+            self.emit_params_changed(Parameter::ClaimPeriod, old_value, milliseconds);
+            Ok(())
+        }
+
+        /// Administrative function: set the blocks after the close of a round
+        /// (with block number deadlines) to claim the rewards, for the next closed rounds,
+        /// at least `MIN_CLAIM_BLOCKS`.
+        #[ink(message)]
+        pub fn set_claim_blocks(&mut self, blocks: BlockNumber) -> Result<()> {
+            self.is_caller_allowed(Permission::ParameterManager)?;
+            self.is_single_approval()?;
+            self.do_set_claim_blocks(blocks)
+        }
+
+        fn do_set_claim_blocks(&mut self, blocks: BlockNumber) -> Result<()> {
+            if blocks < MIN_CLAIM_BLOCKS {
+                return Err(Error::InvalidClaimPeriod);
+            }
+
+            let old_value = self.claim_blocks;
+            self.claim_blocks = blocks;
+
+            self.emit_params_changed(Parameter::ClaimBlocks, old_value.into(), blocks.into());
+            Ok(())
+        }
+
+        /// Get the time after the close of a round to claim the rewards.
+        #[ink(message)]
+        pub fn get_claim_period(&self) -> Timestamp {
+            self.claim_period
+        }
+
+        /// Get the blocks after the close of a round (with block number deadlines)
+        /// to claim the rewards.
+        #[ink(message)]
+        pub fn get_claim_blocks(&self) -> BlockNumber {
+            self.claim_blocks
+        }
+
+        /// Get the time after the end of a round from which anyone can close it.
+        #[ink(message)]
+        pub fn get_close_grace_period(&self) -> Timestamp {
+            self.close_grace_period
+        }
+
+        /// Get the blocks after the end of a round (with block number deadlines)
+        /// from which anyone can close it.
+        #[ink(message)]
+        pub fn get_close_grace_blocks(&self) -> BlockNumber {
+            self.close_grace_blocks
+        }
+
+        /// Get the tip for whoever closes a round after the grace period, in parts per thousand.
         #[ink(message)]
-        pub fn close_round(&mut self) -> Result<()> {
-            self.is_caller_admin()?;
-
-            let round = self.rounds.get(self.current_round_id);
-
-            if round.is_none() {
-                return Err(Error::IsNoActiveRound);
-            }
+        pub fn get_keeper_tip_per_mille(&self) -> u16 {
+            self.keeper_tip_per_mille
+        }
 
-            let mut round = round.unwrap(); // unwrap is safe here
+        /// Get the minimum time for a round.
+        #[ink(message)]
+        pub fn get_min_elapsed_milliseconds(&self) -> Timestamp {
+            self.min_elapsed_milliseconds
+        }
 
-            if round.is_finished {
-                return Err(Error::IsNoActiveRound);
-            }
-            if round.finish_at > self.env().block_timestamp() {
-                return Err(Error::NotYetFinishedRound);
-            }
+        /// Get the maximum time for a round.
+        #[ink(message)]
+        pub fn get_max_elapsed_milliseconds(&self) -> Timestamp {
+            self.max_elapsed_milliseconds
+        }
 
-            let mut total_votes = 0;
-            let mut total_reputation = 0;
-            let mut contributors = Vec::new();
+        /// Get the minimum time for a round with block number deadlines, in blocks.
+        #[ink(message)]
+        pub fn get_min_elapsed_blocks(&self) -> BlockNumber {
+            self.min_elapsed_blocks
+        }
 
-            for contributor_id in self.contributors_list.get().unwrap().iter() {
-                let mut contributor = self.contributors.get(contributor_id).unwrap();
+        /// Get the maximum time for a round with block number deadlines, in blocks.
+        #[ink(message)]
+        pub fn get_max_elapsed_blocks(&self) -> BlockNumber {
+            self.max_elapsed_blocks
+        }
 
-                total_votes += contributor.votes_submitted;
-                total_reputation += contributor.reputation;
-                contributors.push((contributor_id.to_owned(), contributor.reputation));
+        /// Get the deadline unit of the rounds that do not choose one.
+        #[ink(message)]
+        pub fn get_deadline_unit(&self) -> DeadlineUnit {
+            self.deadline_unit
+        }
 
-                // Reset
-                contributor.reputation = 1;
-                contributor.votes_submitted = 0;
-                self.contributors.insert(contributor_id, &contributor);
+        /// Get the funds of the organization in the native currency: total, reserved by the rounds,
+        /// free for new rounds and the existential deposit.
+        #[ink(message)]
+        pub fn get_treasury(&self) -> Treasury {
+            Treasury {
+                total: self.env().balance(),
+                reserved: self.reserved_funds,
+                free: self.free_funds(),
+                existential_deposit: self.env().minimum_balance(),
             }
+        }
 
-            // unwrap is safe here: total_reputation != 0
-            let min_fraction = round.value.checked_div(total_reputation.into()).unwrap();
-
-            for contributor in contributors.iter() {
-                let amount = min_fraction
-                    .checked_mul(contributor.1.into())
-                    .ok_or(Error::MulOverflow(min_fraction, contributor.1.into()))?; // should not happen
+        /// Get the funds of the organization in a PSP22 token: total, reserved by the rounds
+        /// and free for new rounds.
+        #[ink(message)]
+        pub fn get_token_treasury(&self, token: AccountId) -> Result<Treasury> {
+            let total = self.token_balance(token)?;
+            let reserved = self.reserved_tokens.get(token).unwrap_or(0);
+
+            Ok(Treasury {
+                total,
+                reserved,
+                free: total.saturating_sub(reserved),
+                existential_deposit: 0,
+            })
+        }
 
-                self.env()
-                    .transfer(contributor.0, amount)
-                    .map_err(|_| Error::TransferFailed(contributor.0, amount))?;
+        /// Deposits the transferred funds in the organization, recording the caller as donor.
+        #[ink(message, payable)]
+        pub fn deposit(&mut self, memo: String) -> Result<()> {
+            if memo.len() > MAX_MEMO_LENGTH {
+                return Err(Error::MemoTooLong);
             }
+            self.add_donation(DepositTarget::Organization, memo)?;
+            Ok(())
+        }
 
-            contributors.sort_by(|a, b| a.1.cmp(&b.1)); // sorted from lowest to highest by reputation
-
-            // The last element is the most reputable
-            self.send_nft("Gold", contributors.pop())?;
-
-            // The second to last element is the second in reputation
-            self.send_nft("Silver", contributors.pop())?;
+        /// Adds the transferred funds to the value of a round not yet finished
+        /// paid in the native currency, recording the caller as donor.
+        #[ink(message, payable)]
+        pub fn fund_round(&mut self, round_id: RoundId) -> Result<()> {
+            let mut round = self.rounds.get(round_id).ok_or(Error::RoundNotExist)?;
+
+            match round.status_at(self.now_in(round.unit)) {
+                RoundStatus::Scheduled | RoundStatus::Voting => {}
+                RoundStatus::AwaitingClose => return Err(Error::RoundVotingEnded),
+                RoundStatus::Closing | RoundStatus::Finished | RoundStatus::Cancelled => {
+                    return Err(Error::IsNoActiveRound)
+                }
+            }
 
-            // The third to last element is the third in reputation
-            self.send_nft("Bronze", contributors.pop())?;
+            if round.asset != RewardAsset::Native {
+                return Err(Error::RewardAssetMismatch);
+            }
 
-            round.is_finished = true;
-            self.rounds.insert(self.current_round_id, &round);
+            let amount = self.add_donation(DepositTarget::Round(round_id), String::new())?;
 
-            self.env().emit_event(CloseRound {
-                round_id: self.current_round_id,
-                total_votes,
-                total_reputation,
-            });
+            // the transferred funds are already in the balance, so they are free
+            self.reserve_funds(RewardAsset::Native, amount)?;
+            round.value = round.value.saturating_add(amount);
+            self.rounds.insert(round_id, &round);
 
             Ok(())
         }
 
-        /// Administrative function: set the minimum time for a round, for the next round.
+        /// Get the total deposited by a donor.
         #[ink(message)]
-        pub fn set_min_elapsed_milliseconds(&mut self, milliseconds: Timestamp) -> Result<()> {
-            self.is_caller_admin()?;
-            self.min_elapsed_milliseconds = milliseconds;
-            Ok(())
+        pub fn get_donor_total(&self, donor: AccountId) -> Balance {
+            self.donations.get(donor).unwrap_or(0)
         }
 
-        /// Get the minimum time for a round.
+        /// Get the total deposited by all the donors.
         #[ink(message)]
-        pub fn get_min_elapsed_milliseconds(&self) -> Timestamp {
-            self.min_elapsed_milliseconds
+        pub fn get_total_donations(&self) -> Balance {
+            self.total_donations
         }
 
         /// Get the address of the contract
@@ -418,14 +3355,25 @@ mod organization {
         pub fn get_block_timestamp(&self) -> Timestamp {
             self.env().block_timestamp()
         }
+
+        #[ink(message)]
+        pub fn get_block_number(&self) -> BlockNumber {
+            self.env().block_number()
+        }
     }
 
     /////////////////////////////////////////////////////////////////////////////////////
 
     impl VoteTrait for Organization {
-        /// Submit a vote, the caller (`emitter_id`) gives the vote to `receiver_id`.
+        /// Submit a vote in the round `round_id`,
+        /// the caller (`emitter_id`) gives the vote to `receiver_id`.
         #[ink(message)]
-        fn submit_vote(&mut self, receiver_id: AccountId, vote: Vote) -> Result<()> {
+        fn submit_vote(
+            &mut self,
+            round_id: RoundId,
+            receiver_id: AccountId,
+            vote: Vote,
+        ) -> Result<()> {
             let emitter_id = self.env().caller();
             let emitter_member = self.members.get(emitter_id);
             let receiver_member = self.members.get(receiver_id);
@@ -442,34 +3390,60 @@ mod organization {
                 return Err(Error::CannotVoteItself);
             }
 
-            let round = self.rounds.get(self.current_round_id);
-            if round.is_none() || round.clone().unwrap().finish_at < self.env().block_timestamp() {
-                return Err(Error::IsNoActiveRound);
-            }
+            let mut round = self.get_open_round(round_id)?;
 
             // unwraps is safe here
-            let round = round.unwrap();
             let emitter = self.contributors.get(emitter_id).unwrap();
-            let mut receiver = self.contributors.get(receiver_id).unwrap();
+            let receiver = self.contributors.get(receiver_id).unwrap();
+
+            // only the contributors that joined before the round was created
+            self.is_eligible_voter(&round, round_id, emitter_id)?;
+            self.is_eligible_voter(&round, round_id, receiver_id)?;
+
+            if emitter.status == MemberStatus::Suspended
+                || receiver.status == MemberStatus::Suspended
+            {
+                return Err(Error::ContributorIsSuspended);
+            }
 
             if vote.value > round.max_votes {
                 return Err(Error::ExceedsVoteLimit(round.max_votes));
             }
 
-            if emitter.votes_submitted + vote.value > round.max_votes {
+            let mut emitter = self.get_participation(round_id, emitter_id);
+            let mut receiver = self.get_participation(round_id, receiver_id);
+
+            if emitter.votes_submitted.saturating_add(vote.value) > round.max_votes {
                 return Err(Error::ExceedsYourVoteLimit(
                     round.max_votes - emitter.votes_submitted,
                 ));
             }
 
+            // the voters are listed on their first vote, the close only visits them
+            self.push_round_voter(round_id, &mut round, emitter_id);
+            self.push_round_voter(round_id, &mut round, receiver_id);
+
+            let old_reputation = receiver.reputation;
+
+            emitter.votes_submitted += vote.value;
+            receiver.votes_received = receiver.votes_received.saturating_add(vote.value.into());
             receiver.reputation = get_reputation(receiver.reputation, emitter.reputation, vote);
 
-            // persist contributor data
-            self.contributors.insert(emitter_id, &emitter); // may not update anything
-            self.contributors.insert(receiver_id, &receiver);
+            // the totals are kept up to date so that the close can be paged
+            round.total_votes = round.total_votes.saturating_add(vote.value.into());
+            round.total_reputation = round
+                .total_reputation
+                .saturating_sub(old_reputation)
+                .saturating_add(receiver.reputation);
+            self.rounds.insert(round_id, &round);
+
+            // persist the participations in the round
+            self.participations.insert((round_id, emitter_id), &emitter);
+            self.participations
+                .insert((round_id, receiver_id), &receiver);
 
             self.env().emit_event(VoteCast {
-                round_id: self.current_round_id,
+                round_id,
                 from: emitter_id,
                 to: receiver_id,
                 value: vote.value,
@@ -478,23 +3452,21 @@ mod organization {
             Ok(())
         }
 
-        /// Getting the reputation of a contributor, from whom it is consulted.
+        /// Getting the reputation of a contributor in the round `round_id`,
+        /// from whom it is consulted.
         #[ink(message)]
-        fn get_reputation(&self) -> Result<Reputation> {
+        fn get_reputation(&self, round_id: RoundId) -> Result<Reputation> {
             let caller_id = self.env().caller();
             let caller_member = self.members.get(caller_id);
 
-            if caller_member.is_none() || caller_member.unwrap() != Role::Admin {
+            if caller_member.is_none() || caller_member.unwrap() != Role::Contributor {
                 return Err(Error::YouAreNotContributor);
             }
 
-            let round = self.rounds.get(self.current_round_id);
-            if round.is_none() || round.clone().unwrap().finish_at < self.env().block_timestamp() {
-                return Err(Error::IsNoActiveRound);
-            }
+            let round = self.get_open_round(round_id)?;
+            self.is_eligible_voter(&round, round_id, caller_id)?;
 
-            let caller = self.contributors.get(caller_id).unwrap(); // unwrap is safe here
-            Ok(caller.reputation)
+            Ok(self.get_participation(round_id, caller_id).reputation)
         }
     }
 
@@ -504,6 +3476,59 @@ mod organization {
     mod unit_tests {
         use super::*;
 
+        #[test]
+        fn round_status_test() {
+            let mut round = Round {
+                name: String::from("round"),
+                value: 100,
+                max_votes: 10,
+                start_at: 1000,
+                finish_at: 2000,
+                unit: DeadlineUnit::Timestamp,
+                asset: RewardAsset::Native,
+                status: RoundStatus::Scheduled,
+                team: None,
+                funded_by_team: 0,
+                voters_count: 0,
+                total_votes: 0,
+                total_reputation: 0,
+                keeper_tip: 0,
+                settled_count: 0,
+                unclaimed: 0,
+                claims_expire_at: 0,
+                badges_issued: false,
+            };
+
+            assert_eq!(round.status_at(0), RoundStatus::Scheduled);
+            assert_eq!(round.status_at(999), RoundStatus::Scheduled);
+            assert_eq!(round.status_at(1000), RoundStatus::Voting);
+            assert_eq!(round.status_at(1999), RoundStatus::Voting);
+            assert_eq!(round.status_at(2000), RoundStatus::AwaitingClose);
+
+            round.status = RoundStatus::Finished;
+            assert_eq!(round.status_at(1500), RoundStatus::Finished);
+
+            round.status = RoundStatus::Cancelled;
+            assert_eq!(round.status_at(3000), RoundStatus::Cancelled);
+
+            round.claims_expire_at = 5000;
+            assert!(!round.is_claim_expired_at(4999));
+            assert!(round.is_claim_expired_at(5000));
+        }
+
+        #[test]
+        fn hours_conversion_test() {
+            assert_eq!(hours_to_milliseconds(1), Ok(3_600_000));
+            assert_eq!(hours_to_milliseconds(1200), Ok(4_320_000_000)); // over u32::MAX
+            assert_eq!(
+                hours_to_milliseconds(u32::MAX),
+                Ok(u32::MAX as Timestamp * 3_600_000)
+            );
+
+            assert_eq!(hours_to_blocks(1), Ok(BLOCKS_PER_HOUR));
+            assert_eq!(hours_to_blocks(u32::MAX), Err(Error::DurationOverflow));
+        }
+
         #[test]
         fn get_reputation_test() {
             let vote1positive = Vote {
@@ -554,6 +3579,39 @@ mod organization {
             assert_eq!(get_reputation(10, 1, vote10negative), 1);
             assert_eq!(get_reputation(10, 10, vote10negative), 1);
         }
+
+        #[test]
+        fn validate_profile_test() {
+            let profile = Profile {
+                display_name: String::from("Alice"),
+                handle: String::from("alice"),
+                avatar_uri: String::from("ipfs://avatar"),
+            };
+
+            assert_eq!(validate_profile(&profile), Ok(()));
+            assert_eq!(validate_profile(&Profile::default()), Ok(()));
+
+            let long_name = Profile {
+                display_name: "a".repeat(MAX_DISPLAY_NAME_LENGTH + 1),
+                ..profile.clone()
+            };
+            assert_eq!(validate_profile(&long_name), Err(Error::DisplayNameTooLong));
+
+            let long_handle = Profile {
+                handle: "a".repeat(MAX_HANDLE_LENGTH + 1),
+                ..profile.clone()
+            };
+            assert_eq!(validate_profile(&long_handle), Err(Error::HandleTooLong));
+
+            let long_avatar_uri = Profile {
+                avatar_uri: "a".repeat(MAX_AVATAR_URI_LENGTH + 1),
+                ..profile
+            };
+            assert_eq!(
+                validate_profile(&long_avatar_uri),
+                Err(Error::AvatarUriTooLong)
+            );
+        }
     }
 
     //---------------------------------------------------------------------------------//
@@ -602,7 +3660,8 @@ mod organization {
                     .code_hash;
 
                 let min_elapsed_hours = 1;
-                let contract_ref = OrganizationRef::new($admin_account.id, nft_code_hash, min_elapsed_hours);
+                let max_elapsed_hours = 24 * 365;
+                let contract_ref = OrganizationRef::new($admin_account.id, nft_code_hash, min_elapsed_hours, max_elapsed_hours, DeadlineUnit::Timestamp);
                 let $contract_id = $client
                     .instantiate("organization", &$admin_account.key, contract_ref, 0, None)
                     .await
@@ -613,6 +3672,99 @@ mod organization {
             };
         }
 
+        // Closes the round 1 of 1000 with block number deadlines, where bob votes charlie.
+        macro_rules! close_voted_round {
+            (
+                $client:ident, $contract_id:ident, $admin:ident,
+                $emitter:ident, $receiver:ident
+            ) => {
+                let deposit = build_message::<OrganizationRef>($contract_id.clone())
+                    .call(|contract| contract.deposit(String::new()));
+                assert!($client.call(&$admin.key, deposit, 1000, None).await.is_ok());
+
+                for account_id in [$emitter.id, $receiver.id] {
+                    let add_contributor = build_message::<OrganizationRef>($contract_id.clone())
+                        .call(|contract| contract.add_contributor(account_id));
+                    assert!($client
+                        .call(&$admin.key, add_contributor, 0, None)
+                        .await
+                        .is_ok());
+                }
+
+                let set_min_elapsed_blocks = build_message::<OrganizationRef>($contract_id.clone())
+                    .call(|contract| contract.set_min_elapsed_blocks(1));
+                assert!($client
+                    .call(&$admin.key, set_min_elapsed_blocks, 0, None)
+                    .await
+                    .is_ok());
+
+                let get_block_number = build_message::<OrganizationRef>($contract_id.clone())
+                    .call(|contract| contract.get_block_number());
+                let now = $client
+                    .call_dry_run(&$admin.key, &get_block_number, 0, None)
+                    .await
+                    .return_value();
+
+                let open_round =
+                    build_message::<OrganizationRef>($contract_id.clone()).call(|contract| {
+                        let finish_at = Timestamp::from(now) + 3;
+                        let unit = Some(DeadlineUnit::BlockNumber);
+                        contract.open_round(
+                            String::from("round"),
+                            1000,
+                            10,
+                            0,
+                            finish_at,
+                            unit,
+                            None,
+                        )
+                    });
+                assert!($client.call(&$admin.key, open_round, 0, None).await.is_ok());
+
+                let vote = Vote {
+                    sign: VoteSign::Positive,
+                    value: 5,
+                };
+                let submit_vote = build_message::<OrganizationRef>($contract_id.clone())
+                    .call(|contract| contract.submit_vote(1, $receiver.id, vote));
+                assert!($client
+                    .call(&$emitter.key, submit_vote, 0, None)
+                    .await
+                    .is_ok());
+
+                // every call is a new block, the round can be closed once the voting ends
+                let mut closed = false;
+
+                for _ in 0..10 {
+                    let close_round = build_message::<OrganizationRef>($contract_id.clone())
+                        .call(|contract| contract.close_round(1));
+                    let close_round_return = $client
+                        .call_dry_run(&$admin.key, &close_round, 0, None)
+                        .await
+                        .return_value();
+
+                    if close_round_return == Err(Error::NotYetFinishedRound) {
+                        let get_block_number =
+                            build_message::<OrganizationRef>($contract_id.clone())
+                                .call(|contract| contract.get_block_number());
+                        assert!($client
+                            .call(&$admin.key, get_block_number, 0, None)
+                            .await
+                            .is_ok());
+                        continue;
+                    }
+
+                    closed = $client
+                        .call(&$admin.key, close_round, 0, None)
+                        .await
+                        .is_ok();
+                    break;
+                }
+
+                assert!(closed);
+            };
+        }
+
         //--------------------------------//
 
         #[ink_e2e::test]
@@ -646,5 +3798,577 @@ mod organization {
 
             Ok(())
         }
+
+        #[ink_e2e::test]
+        async fn multisig_add_admin_test(mut client: ink_e2e::Client<C, E>) -> E2EResult {
+            init_e2e!(client, contract_id, alice, bob, charlie);
+
+            let add_admin = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.add_admin(bob.id));
+            let add_admin_return = client.call(&alice.key, add_admin, 0, None).await;
+
+            assert!(add_admin_return.is_ok());
+
+            let set_approval_threshold = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.set_approval_threshold(2));
+            let set_approval_threshold_return = client
+                .call(&alice.key, set_approval_threshold, 0, None)
+                .await;
+
+            assert!(set_approval_threshold_return.is_ok());
+
+            let add_admin = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.add_admin(charlie.id));
+            let add_admin_return = client.call(&alice.key, add_admin, 0, None).await;
+
+            assert!(add_admin_return.is_err());
+
+            let propose_action =
+                build_message::<OrganizationRef>(contract_id.clone()).call(|contract| {
+                    contract.propose_action(AdminAction::AddAdmin(charlie.id), u64::MAX)
+                });
+            let propose_action_return = client.call(&alice.key, propose_action, 0, None).await;
+
+            assert!(propose_action_return.is_ok());
+
+            let approve_action = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.approve_action(1));
+            let approve_action_return = client.call(&bob.key, approve_action, 0, None).await;
+
+            assert!(approve_action_return.is_ok());
+
+            let get_pending_action = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.get_pending_action(1));
+            let get_pending_action_return = client
+                .call_dry_run(&alice.key, &get_pending_action, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(get_pending_action_return, None);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn revoke_permission_test(mut client: ink_e2e::Client<C, E>) -> E2EResult {
+            init_e2e!(client, contract_id, alice, bob);
+
+            let add_admin = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.add_admin(bob.id));
+            let add_admin_return = client.call(&alice.key, add_admin, 0, None).await;
+
+            assert!(add_admin_return.is_ok());
+
+            let revoke_permission = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.revoke_permission(bob.id, Permission::RoundManager));
+            let revoke_permission_return =
+                client.call(&alice.key, revoke_permission, 0, None).await;
+
+            assert!(revoke_permission_return.is_ok());
+
+            let has_permission = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.has_permission(bob.id, Permission::RoundManager));
+            let has_permission_return = client
+                .call_dry_run(&alice.key, &has_permission, 0, None)
+                .await
+                .return_value();
+
+            assert!(!has_permission_return);
+
+            let get_block_timestamp = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.get_block_timestamp());
+            let now = client
+                .call_dry_run(&alice.key, &get_block_timestamp, 0, None)
+                .await
+                .return_value();
+
+            let open_round =
+                build_message::<OrganizationRef>(contract_id.clone()).call(|contract| {
+                    let finish_at = now + 2 * 60 * 60 * 1000;
+                    contract.open_round(String::from("round"), 0, 1, 0, finish_at, None, None)
+                });
+            let open_round_return = client.call(&alice.key, open_round, 0, None).await;
+
+            assert!(open_round_return.is_ok());
+
+            let close_round = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.close_round(1));
+            let close_round_return = client
+                .call_dry_run(&bob.key, &close_round, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(
+                close_round_return,
+                Err(Error::MissingPermission(Permission::RoundManager))
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn late_contributor_test(mut client: ink_e2e::Client<C, E>) -> E2EResult {
+            init_e2e!(client, contract_id, alice, bob, charlie, dave);
+
+            for account_id in [bob.id, charlie.id] {
+                let add_contributor = build_message::<OrganizationRef>(contract_id.clone())
+                    .call(|contract| contract.add_contributor(account_id));
+                let add_contributor_return =
+                    client.call(&alice.key, add_contributor, 0, None).await;
+
+                assert!(add_contributor_return.is_ok());
+            }
+
+            let get_block_timestamp = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.get_block_timestamp());
+            let now = client
+                .call_dry_run(&alice.key, &get_block_timestamp, 0, None)
+                .await
+                .return_value();
+
+            let open_round =
+                build_message::<OrganizationRef>(contract_id.clone()).call(|contract| {
+                    let finish_at = now + 2 * 60 * 60 * 1000;
+                    contract.open_round(String::from("round"), 0, 10, 0, finish_at, None, None)
+                });
+            let open_round_return = client.call(&alice.key, open_round, 0, None).await;
+
+            assert!(open_round_return.is_ok());
+
+            // dave joins after the round was created
+            let add_contributor = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.add_contributor(dave.id));
+            let add_contributor_return = client.call(&alice.key, add_contributor, 0, None).await;
+
+            assert!(add_contributor_return.is_ok());
+
+            let vote = Vote {
+                sign: VoteSign::Positive,
+                value: 1,
+            };
+
+            let submit_vote = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.submit_vote(1, charlie.id, vote));
+            let submit_vote_return = client.call(&bob.key, submit_vote, 0, None).await;
+
+            assert!(submit_vote_return.is_ok());
+
+            let submit_vote = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.submit_vote(1, charlie.id, vote));
+            let submit_vote_return = client
+                .call_dry_run(&dave.key, &submit_vote, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(submit_vote_return, Err(Error::NotEligibleVoter));
+
+            let submit_vote = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.submit_vote(1, dave.id, vote));
+            let submit_vote_return = client
+                .call_dry_run(&bob.key, &submit_vote, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(submit_vote_return, Err(Error::NotEligibleVoter));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn round_funds_test(mut client: ink_e2e::Client<C, E>) -> E2EResult {
+            init_e2e!(client, contract_id, alice);
+
+            let get_block_timestamp = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.get_block_timestamp());
+            let now = client
+                .call_dry_run(&alice.key, &get_block_timestamp, 0, None)
+                .await
+                .return_value();
+            let finish_at = now + 2 * 60 * 60 * 1000;
+
+            let open_round =
+                build_message::<OrganizationRef>(contract_id.clone()).call(|contract| {
+                    contract.open_round(String::from("round"), 1000, 1, 0, finish_at, None, None)
+                });
+            let open_round_return = client
+                .call_dry_run(&alice.key, &open_round, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(open_round_return, Err(Error::InsufficientFunds));
+
+            let deposit = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.deposit(String::new()));
+            let deposit_return = client.call(&alice.key, deposit, 1000, None).await;
+
+            assert!(deposit_return.is_ok());
+
+            let open_round =
+                build_message::<OrganizationRef>(contract_id.clone()).call(|contract| {
+                    contract.open_round(String::from("round"), 1000, 1, 0, finish_at, None, None)
+                });
+            let open_round_return = client.call(&alice.key, open_round, 0, None).await;
+
+            assert!(open_round_return.is_ok());
+
+            // the funds are reserved for the open round
+            let open_round =
+                build_message::<OrganizationRef>(contract_id.clone()).call(|contract| {
+                    contract.open_round(String::from("round"), 1000, 1, 0, finish_at, None, None)
+                });
+            let open_round_return = client
+                .call_dry_run(&alice.key, &open_round, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(open_round_return, Err(Error::InsufficientFunds));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn cancel_team_round_test(mut client: ink_e2e::Client<C, E>) -> E2EResult {
+            init_e2e!(client, contract_id, alice, bob);
+
+            let deposit = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.deposit(String::new()));
+            let deposit_return = client.call(&alice.key, deposit, 1000, None).await;
+
+            assert!(deposit_return.is_ok());
+
+            let create_team = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.create_team(String::from("core")));
+            let create_team_return = client.call(&alice.key, create_team, 0, None).await;
+
+            assert!(create_team_return.is_ok());
+
+            let set_team_budget = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.set_team_budget(1, 600));
+            let set_team_budget_return = client.call(&alice.key, set_team_budget, 0, None).await;
+
+            assert!(set_team_budget_return.is_ok());
+
+            let get_block_timestamp = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.get_block_timestamp());
+            let now = client
+                .call_dry_run(&alice.key, &get_block_timestamp, 0, None)
+                .await
+                .return_value();
+            let finish_at = now + 2 * 60 * 60 * 1000;
+
+            let open_team_round =
+                build_message::<OrganizationRef>(contract_id.clone()).call(|contract| {
+                    contract.open_team_round(1, String::from("round"), 400, 1, 0, finish_at, None)
+                });
+            let open_team_round_return = client.call(&alice.key, open_team_round, 0, None).await;
+
+            assert!(open_team_round_return.is_ok());
+
+            let amend_round = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.amend_round(1, 500, 1, finish_at));
+            let amend_round_return = client.call(&alice.key, amend_round, 0, None).await;
+
+            assert!(amend_round_return.is_ok());
+
+            let get_team = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.get_team(1));
+            let get_team_return = client
+                .call_dry_run(&alice.key, &get_team, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(get_team_return.map(|team| team.budget), Some(100));
+
+            // a donation on top of the team budget
+            let fund_round = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.fund_round(1));
+            let fund_round_return = client.call(&bob.key, fund_round, 300, None).await;
+
+            assert!(fund_round_return.is_ok());
+
+            let get_treasury = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.get_treasury());
+            let get_treasury_return = client
+                .call_dry_run(&alice.key, &get_treasury, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(get_treasury_return.reserved, 900);
+
+            let cancel_round = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.cancel_round(1, String::from("wrong value")));
+            let cancel_round_return = client.call(&alice.key, cancel_round, 0, None).await;
+
+            assert!(cancel_round_return.is_ok());
+
+            // only the part taken from the budget goes back to the team
+            let get_team = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.get_team(1));
+            let get_team_return = client
+                .call_dry_run(&alice.key, &get_team, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(get_team_return.map(|team| team.budget), Some(600));
+
+            // the donation becomes free, the budget remains reserved
+            let get_treasury = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.get_treasury());
+            let get_treasury_return = client
+                .call_dry_run(&alice.key, &get_treasury, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(get_treasury_return.reserved, 600);
+            assert_eq!(
+                get_treasury_return.free,
+                get_treasury_return.total - get_treasury_return.existential_deposit - 600
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn claim_reward_test(mut client: ink_e2e::Client<C, E>) -> E2EResult {
+            init_e2e!(client, contract_id, alice, bob, charlie);
+            close_voted_round!(client, contract_id, alice, bob, charlie);
+
+            let get_pending_rewards = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.get_pending_rewards(charlie.id));
+            let get_pending_rewards_return = client
+                .call_dry_run(&alice.key, &get_pending_rewards, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(get_pending_rewards_return.len(), 1);
+            assert_eq!(get_pending_rewards_return[0].0, 1);
+            assert!(get_pending_rewards_return[0].1 > 0);
+
+            // the rewards are reserved during the claim period
+            let sweep_unclaimed_rewards = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.sweep_unclaimed_rewards(1));
+            let sweep_unclaimed_rewards_return = client
+                .call_dry_run(&bob.key, &sweep_unclaimed_rewards, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(
+                sweep_unclaimed_rewards_return,
+                Err(Error::ClaimPeriodNotExpired)
+            );
+
+            let claim_reward = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.claim_reward(1));
+            let claim_reward_return = client.call(&charlie.key, claim_reward, 0, None).await;
+
+            assert!(claim_reward_return.is_ok());
+
+            let claim_reward = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.claim_reward(1));
+            let claim_reward_return = client
+                .call_dry_run(&charlie.key, &claim_reward, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(claim_reward_return, Err(Error::NoRewardToClaim));
+
+            let get_pending_rewards = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.get_pending_rewards(charlie.id));
+            let get_pending_rewards_return = client
+                .call_dry_run(&alice.key, &get_pending_rewards, 0, None)
+                .await
+                .return_value();
+
+            assert!(get_pending_rewards_return.is_empty());
+
+            let claim_all = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.claim_all());
+            let claim_all_return = client.call(&bob.key, claim_all, 0, None).await;
+
+            assert!(claim_all_return.is_ok());
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn claim_period_test(mut client: ink_e2e::Client<C, E>) -> E2EResult {
+            init_e2e!(client, contract_id, alice, bob);
+
+            // a shorter period could void the rewards before they can be claimed
+            let set_claim_blocks = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.set_claim_blocks(MIN_CLAIM_BLOCKS - 1));
+            let set_claim_blocks_return = client
+                .call_dry_run(&alice.key, &set_claim_blocks, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(set_claim_blocks_return, Err(Error::InvalidClaimPeriod));
+
+            let add_admin = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.add_admin(bob.id));
+            let add_admin_return = client.call(&alice.key, add_admin, 0, None).await;
+
+            assert!(add_admin_return.is_ok());
+
+            let set_approval_threshold = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.set_approval_threshold(2));
+            let set_approval_threshold_return = client
+                .call(&alice.key, set_approval_threshold, 0, None)
+                .await;
+
+            assert!(set_approval_threshold_return.is_ok());
+
+            let set_claim_blocks = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.set_claim_blocks(MIN_CLAIM_BLOCKS));
+            let set_claim_blocks_return = client
+                .call_dry_run(&alice.key, &set_claim_blocks, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(set_claim_blocks_return, Err(Error::ApprovalRequired));
+
+            let propose_action =
+                build_message::<OrganizationRef>(contract_id.clone()).call(|contract| {
+                    contract.propose_action(AdminAction::SetClaimBlocks(MIN_CLAIM_BLOCKS), u64::MAX)
+                });
+            let propose_action_return = client.call(&alice.key, propose_action, 0, None).await;
+
+            assert!(propose_action_return.is_ok());
+
+            let approve_action = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.approve_action(1));
+            let approve_action_return = client.call(&bob.key, approve_action, 0, None).await;
+
+            assert!(approve_action_return.is_ok());
+
+            let get_claim_blocks = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.get_claim_blocks());
+            let get_claim_blocks_return = client
+                .call_dry_run(&alice.key, &get_claim_blocks, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(get_claim_blocks_return, MIN_CLAIM_BLOCKS);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn import_members_test(mut client: ink_e2e::Client<C, E>) -> E2EResult {
+            init_e2e!(client, contract_id, alice, bob, charlie);
+
+            let members = vec![(bob.id, Role::Contributor), (charlie.id, Role::Admin)];
+
+            let import_members = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.import_members(members.clone()));
+            let import_members_return = client.call(&alice.key, import_members, 0, None).await;
+
+            assert!(import_members_return.is_ok());
+
+            // the members already imported are skipped
+            let import_members = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.import_members(members.clone()));
+            let import_members_return = client.call(&alice.key, import_members, 0, None).await;
+
+            assert!(import_members_return.is_ok());
+
+            let member_count = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.member_count());
+            let member_count_return = client
+                .call_dry_run(&alice.key, &member_count, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(member_count_return, 3);
+
+            let get_role = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.get_role(charlie.id));
+            let get_role_return = client
+                .call_dry_run(&alice.key, &get_role, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(get_role_return, Some(Role::Admin));
+
+            let members = vec![(bob.id, Role::Contributor); MAX_IMPORT_BATCH_SIZE + 1];
+
+            let import_members = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.import_members(members.clone()));
+            let import_members_return = client
+                .call_dry_run(&alice.key, &import_members, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(import_members_return, Err(Error::ImportBatchTooLarge));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn approve_application_test(mut client: ink_e2e::Client<C, E>) -> E2EResult {
+            init_e2e!(client, contract_id, alice, bob);
+
+            let apply_as_contributor = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.apply_as_contributor(String::from("I write docs")));
+            let apply_as_contributor_return =
+                client.call(&bob.key, apply_as_contributor, 0, None).await;
+
+            assert!(apply_as_contributor_return.is_ok());
+
+            let approve_application = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.approve_application(bob.id));
+            let approve_application_return =
+                client.call(&alice.key, approve_application, 0, None).await;
+
+            assert!(approve_application_return.is_ok());
+
+            let get_role = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.get_role(bob.id));
+            let get_role_return = client
+                .call_dry_run(&alice.key, &get_role, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(get_role_return, Some(Role::Contributor));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn admin_transfer_test(mut client: ink_e2e::Client<C, E>) -> E2EResult {
+            init_e2e!(client, contract_id, alice, bob);
+
+            let renounce_admin = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.renounce_admin());
+            let renounce_admin_return = client.call(&alice.key, renounce_admin, 0, None).await;
+
+            assert!(renounce_admin_return.is_err()); // the last administrator
+
+            let propose_admin_transfer = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.propose_admin_transfer(bob.id));
+            let propose_admin_transfer_return = client
+                .call(&alice.key, propose_admin_transfer, 0, None)
+                .await;
+
+            assert!(propose_admin_transfer_return.is_ok());
+
+            let accept_admin_transfer = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.accept_admin_transfer(alice.id));
+            let accept_admin_transfer_return =
+                client.call(&bob.key, accept_admin_transfer, 0, None).await;
+
+            assert!(accept_admin_transfer_return.is_ok());
+
+            let list_admins = build_message::<OrganizationRef>(contract_id.clone())
+                .call(|contract| contract.list_admins(0, 10));
+            let list_admins_return = client
+                .call_dry_run(&bob.key, &list_admins, 0, None)
+                .await
+                .return_value();
+
+            assert_eq!(list_admins_return, vec![bob.id]);
+
+            Ok(())
+        }
     }
 }
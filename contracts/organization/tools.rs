@@ -1,3 +1,6 @@
+use core::ops::Range;
+use ink::prelude::vec::Vec;
+
 /// Function that computes the approximate square root of a number (fast)
 pub fn sqrt_fast(v: i64) -> i64 {
     // https://github.com/chmike/fpsqrt/blob/df099181030e95d663d89e87d4bf2d36534776a5/fpsqrt.c#L51
@@ -23,6 +26,60 @@ pub fn sqrt_fast(v: i64) -> i64 {
     q as i64
 }
 
+/// Maximum number of elements returned by a paginated query.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Function that returns the range of indexes of a page, starting at `offset`,
+/// with at most `limit` indexes (bounded by `MAX_PAGE_SIZE`) lower than `len`.
+pub fn page_range(offset: u32, limit: u32, len: u32) -> Range<u32> {
+    let start = offset.min(len);
+    let end = offset.saturating_add(limit.min(MAX_PAGE_SIZE)).min(len);
+    start..end
+}
+
+/// Function that returns a page of elements, starting at `offset`,
+/// with at most `limit` elements (bounded by `MAX_PAGE_SIZE`).
+pub fn paginate<T: Clone>(items: &[T], offset: u32, limit: u32) -> Vec<T> {
+    let range = page_range(offset, limit, items.len() as u32);
+    items[range.start as usize..range.end as usize].to_vec()
+}
+
+/// Function that computes a fraction of a value, in parts per thousand (rounded down),
+/// without overflowing for any value.
+pub fn per_mille(value: u128, parts: u16) -> u128 {
+    let parts = parts as u128;
+    value / 1000 * parts + value % 1000 * parts / 1000
+}
+
+/// Function that computes the part of a value in proportion to `weight` of `total`
+/// (rounded down) without overflowing, the parts of weights adding up to `total`
+/// add up at most to the value.
+pub fn share(value: u128, weight: u32, total: u32) -> u128 {
+    if total == 0 {
+        return 0;
+    }
+
+    let weight = u128::from(weight.min(total));
+    let total = u128::from(total);
+
+    // value * weight / total, split to avoid the overflow
+    value / total * weight + value % total * weight / total
+}
+
+/// Function that adds an item to a ranking of at most `size` items, sorted from highest
+/// to lowest weight, on equal weights the items added first go first.
+pub fn rank<T>(ranking: &mut Vec<(T, u32)>, item: T, weight: u32, size: usize) {
+    let position = ranking
+        .iter()
+        .position(|(_, w)| weight > *w)
+        .unwrap_or(ranking.len());
+
+    if position < size {
+        ranking.insert(position, (item, weight));
+        ranking.truncate(size);
+    }
+}
+
 #[cfg(test)]
 mod unit_tests {
     use super::*;
@@ -36,4 +93,73 @@ mod unit_tests {
         assert_eq!(sqrt_fast(100), 10); //  10
         assert_eq!(sqrt_fast(500), 22); //  22.36…
     }
+
+    #[test]
+    fn per_mille_test() {
+        assert_eq!(per_mille(1000, 10), 10);
+        assert_eq!(per_mille(12345, 10), 123); // 123.45
+        assert_eq!(per_mille(999, 1), 0); //        0.999
+        assert_eq!(per_mille(500, 1000), 500);
+        assert_eq!(per_mille(7, 0), 0);
+        assert_eq!(per_mille(u128::MAX, 1000), u128::MAX);
+    }
+
+    #[test]
+    fn share_test() {
+        assert_eq!(share(100, 1, 3), 33);
+        assert_eq!(share(10, 5, 10), 5);
+        assert_eq!(share(7, 2, 7), 2);
+        assert_eq!(share(2, 1, 5), 0); // 0.4
+        assert_eq!(share(0, 3, 7), 0);
+        assert_eq!(share(100, 0, 0), 0);
+        assert_eq!(share(100, 5, 4), 100); // the weight is at most the total
+        assert_eq!(share(u128::MAX, u32::MAX, u32::MAX), u128::MAX);
+
+        // the parts never exceed the value
+        let parts = [u32::MAX - 8, 1, 7].map(|w| share(u128::MAX, w, u32::MAX));
+        assert!(parts
+            .iter()
+            .try_fold(0u128, |sum, p| sum.checked_add(*p))
+            .is_some());
+    }
+
+    #[test]
+    fn rank_test() {
+        let mut ranking = Vec::new();
+
+        rank(&mut ranking, 'a', 5, 3);
+        rank(&mut ranking, 'b', 7, 3);
+        rank(&mut ranking, 'c', 5, 3);
+        assert_eq!(ranking, [('b', 7), ('a', 5), ('c', 5)]);
+
+        rank(&mut ranking, 'd', 5, 3); // equal weights keep the first added
+        assert_eq!(ranking, [('b', 7), ('a', 5), ('c', 5)]);
+
+        rank(&mut ranking, 'e', 6, 3);
+        assert_eq!(ranking, [('b', 7), ('e', 6), ('a', 5)]);
+
+        rank(&mut ranking, 'f', 1, 0);
+        assert_eq!(ranking.len(), 3);
+    }
+
+    #[test]
+    fn page_range_test() {
+        assert_eq!(page_range(0, 3, 250), 0..3);
+        assert_eq!(page_range(248, 5, 250), 248..250);
+        assert_eq!(page_range(300, 5, 250), 250..250);
+        assert_eq!(page_range(u32::MAX, u32::MAX, 250), 250..250);
+        assert_eq!(page_range(0, 1000, 250), 0..MAX_PAGE_SIZE);
+    }
+
+    #[test]
+    fn paginate_test() {
+        let items: Vec<u32> = (1..=250).collect();
+
+        assert_eq!(paginate(&items, 0, 3), [1, 2, 3]);
+        assert_eq!(paginate(&items, 10, 2), [11, 12]);
+        assert_eq!(paginate(&items, 248, 5), [249, 250]);
+        assert_eq!(paginate(&items, 250, 5), []);
+        assert_eq!(paginate(&items, 0, 0), []);
+        assert_eq!(paginate(&items, 0, 1000).len(), MAX_PAGE_SIZE as usize);
+    }
 }
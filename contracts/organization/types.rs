@@ -1,9 +1,11 @@
 use ink::env::{DefaultEnvironment, Environment};
+use ink::prelude::vec::Vec;
 use scale::{Decode, Encode};
 
 pub type AccountId = <DefaultEnvironment as Environment>::AccountId;
 pub type Timestamp = <DefaultEnvironment as Environment>::Timestamp; // milliseconds
 pub type Balance = <DefaultEnvironment as Environment>::Balance;
+pub type Hash = <DefaultEnvironment as Environment>::Hash;
 pub type String = ink::prelude::string::String;
 
 /// Identifier of a round, sequential numbers, starting at one.
@@ -12,9 +14,84 @@ pub type RoundId = u32;
 /// Contributor reputation, starting at one.
 pub type Reputation = u32;
 
+/// The most reputable contributors of a round with their reputation, one per badge.
+pub type Podium = Vec<(AccountId, Reputation)>;
+
 /// Number of votes.
 pub type VotesNumber = u8;
 
+/// Maximum number of rounds open at the same time.
+pub const MAX_ACTIVE_ROUNDS: usize = 8;
+
+/// Maximum number of voters paid in each call to `close_round`.
+pub const MAX_CLOSE_PAGE_SIZE: u32 = 64;
+
+/// Maximum number of rounds in the pending rewards of a contributor.
+pub const MAX_PENDING_REWARDS: usize = 32;
+
+/// Number of blocks produced in an hour, assuming 6 seconds blocks.
+pub const BLOCKS_PER_HOUR: u32 = 600;
+
+/// Time after the end of a round from which anyone can close it, by default (7 days).
+pub const DEFAULT_CLOSE_GRACE_PERIOD: Timestamp = 7 * 24 * 60 * 60 * 1000;
+
+/// Blocks after the end of a round from which anyone can close it, by default (7 days).
+pub const DEFAULT_CLOSE_GRACE_BLOCKS: u32 = 7 * 24 * BLOCKS_PER_HOUR;
+
+/// Minimum time after the end of a round from which anyone can close it (1 day).
+pub const MIN_CLOSE_GRACE_PERIOD: Timestamp = 24 * 60 * 60 * 1000;
+
+/// Minimum blocks after the end of a round from which anyone can close it (1 day).
+pub const MIN_CLOSE_GRACE_BLOCKS: u32 = 24 * BLOCKS_PER_HOUR;
+
+/// Time after the close of a round to claim the rewards, by default (90 days).
+pub const DEFAULT_CLAIM_PERIOD: Timestamp = 90 * 24 * 60 * 60 * 1000;
+
+/// Blocks after the close of a round to claim the rewards, by default (90 days).
+pub const DEFAULT_CLAIM_BLOCKS: u32 = 90 * 24 * BLOCKS_PER_HOUR;
+
+/// Minimum time after the close of a round to claim the rewards (7 days).
+pub const MIN_CLAIM_PERIOD: Timestamp = 7 * 24 * 60 * 60 * 1000;
+
+/// Minimum blocks after the close of a round to claim the rewards (7 days).
+pub const MIN_CLAIM_BLOCKS: u32 = 7 * 24 * BLOCKS_PER_HOUR;
+
+/// Maximum tip for whoever closes a round after the grace period, in parts per thousand.
+pub const MAX_KEEPER_TIP_PER_MILLE: u16 = 50;
+
+/// Maximum length of the motivation of a contributor application, in bytes.
+pub const MAX_MOTIVATION_LENGTH: usize = 512;
+
+/// Maximum length of the display name of a member profile, in bytes.
+pub const MAX_DISPLAY_NAME_LENGTH: usize = 64;
+
+/// Maximum length of the handle of a member profile, in bytes.
+pub const MAX_HANDLE_LENGTH: usize = 32;
+
+/// Maximum length of the avatar URI of a member profile, in bytes.
+pub const MAX_AVATAR_URI_LENGTH: usize = 256;
+
+/// Maximum length of the name of a team, in bytes.
+pub const MAX_TEAM_NAME_LENGTH: usize = 64;
+
+/// Maximum number of members imported in each call to `import_members`.
+pub const MAX_IMPORT_BATCH_SIZE: usize = 50;
+
+/// Maximum length of the memo of a deposit, in bytes.
+pub const MAX_MEMO_LENGTH: usize = 256;
+
+/// Maximum number of teams of an account, as member or lead.
+pub const MAX_TEAMS_PER_MEMBER: usize = 16;
+
+/// Identifier of a team, sequential numbers, starting at one.
+pub type TeamId = u32;
+
+/// Identifier of a pending administrative action, sequential numbers, starting at one.
+pub type ActionId = u32;
+
+/// Number of administrators approvals.
+pub type ApprovalsNumber = u8;
+
 /// Member role.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
 #[cfg_attr(
@@ -26,15 +103,125 @@ pub enum Role {
     Contributor,
 }
 
-/// Information on a contributor's reputation in a specific round.
+/// Administrative permission, granted per administrator.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Permission {
+    /// Adds and removes members, grants and revokes permissions.
+    MembershipManager,
+    /// Opens and closes rounds.
+    RoundManager,
+    /// Manages the organization funds.
+    Treasurer,
+    /// Changes the organization parameters.
+    ParameterManager,
+    /// Issues the badges (NFTs) of the closed rounds.
+    BadgeIssuer,
+}
+
+impl Permission {
+    /// All permissions, granted to every new administrator.
+    pub const ALL: [Permission; 5] = [
+        Permission::MembershipManager,
+        Permission::RoundManager,
+        Permission::Treasurer,
+        Permission::ParameterManager,
+        Permission::BadgeIssuer,
+    ];
+}
+
+/// Member status, a suspended contributor keeps its membership but does not participate.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum MemberStatus {
+    Active,
+    Suspended,
+}
+
+/// Information on a contributor of the organization.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Encode, Decode)]
 #[cfg_attr(
     feature = "std",
     derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
 )]
 pub struct Contributor {
+    pub status: MemberStatus,
+    /// Last round created when it joined, it votes in the rounds created later.
+    pub joined_round: RoundId,
+}
+
+/// Information on a contributor's reputation in a specific round.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Encode, Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Participation {
     pub reputation: Reputation,
     pub votes_submitted: VotesNumber,
+    pub votes_received: u32,
+}
+
+impl Default for Participation {
+    /// Every contributor starts a round with reputation one and no votes.
+    fn default() -> Self {
+        Self {
+            reputation: 1,
+            votes_submitted: 0,
+            votes_received: 0,
+        }
+    }
+}
+
+/// Badge (NFT) awarded to the three most reputable contributors of a round.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Badge {
+    Gold,
+    Silver,
+    Bronze,
+}
+
+impl Badge {
+    /// Badges from the highest to the lowest reputation.
+    pub const ALL: [Badge; 3] = [Badge::Gold, Badge::Silver, Badge::Bronze];
+
+    /// Category of the minted NFT.
+    pub fn category(&self) -> &'static str {
+        match self {
+            Badge::Gold => "Gold",
+            Badge::Silver => "Silver",
+            Badge::Bronze => "Bronze",
+        }
+    }
+}
+
+/// Final result of a contributor in a closed round, kept for audits.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct RoundResult {
+    /// Reputation at the close of the round.
+    pub reputation: Reputation,
+    /// Funds received.
+    pub payout: Balance,
+    /// Votes given to other contributors.
+    pub votes_cast: VotesNumber,
+    /// Votes received from other contributors.
+    pub votes_received: u32,
+    /// Badge received, only the three most reputable contributors get one.
+    pub badge: Option<Badge>,
 }
 
 /// Voting sign, positive adds, negative subtracts,
@@ -60,6 +247,96 @@ pub struct Vote {
     pub value: VotesNumber,
 }
 
+/// Unit of the start and end dates of a round.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum DeadlineUnit {
+    /// Block timestamp, in milliseconds.
+    Timestamp,
+    /// Block number, for chains with unreliable timestamps.
+    BlockNumber,
+}
+
+/// Destination of the funds deposited by a donor.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum DepositTarget {
+    /// Free funds of the organization, available for new rounds.
+    Organization,
+    /// Funds added to the value of a round.
+    Round(RoundId),
+}
+
+/// Asset in which the rewards of a round are paid.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum RewardAsset {
+    /// Native currency of the chain.
+    Native,
+    /// PSP22 token, the address of its contract.
+    Psp22(AccountId),
+}
+
+/// Error of the PSP22 standard (OpenBrush), returned by the token contracts.
+#[derive(Debug, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Psp22Error {
+    Custom(String),
+    InsufficientBalance,
+    InsufficientAllowance,
+    ZeroRecipientAddress,
+    ZeroSenderAddress,
+    SafeTransferCheckFailed(String),
+}
+
+/// Organization parameter, changed by the administrators.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Parameter {
+    MinElapsedMilliseconds,
+    MaxElapsedMilliseconds,
+    MinElapsedBlocks,
+    MaxElapsedBlocks,
+    CloseGracePeriod,
+    CloseGraceBlocks,
+    KeeperTipPerMille,
+    ClaimPeriod,
+    ClaimBlocks,
+}
+
+/// Round lifecycle state.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum RoundStatus {
+    /// Announced, the voting has not started yet.
+    Scheduled,
+    /// Accepting votes.
+    Voting,
+    /// The voting ended, waiting for the funds distribution.
+    AwaitingClose,
+    /// The funds are being distributed, a page of voters per call to `close_round`.
+    Closing,
+    /// The funds were distributed.
+    Finished,
+    /// Discarded without distributing the funds.
+    Cancelled,
+}
+
 /// Information on a round.
 #[derive(Debug, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
 #[cfg_attr(
@@ -73,8 +350,259 @@ pub struct Round {
     pub value: Balance,
     /// Maximum number of votes per contributor.
     pub max_votes: VotesNumber,
-    /// End date of the round (timestamp), in milliseconds.
+    /// Start date of the voting, in `unit`.
+    pub start_at: Timestamp,
+    /// End date of the round, in `unit`.
     pub finish_at: Timestamp,
-    /// Establishes whether the distribution was completed.
-    pub is_finished: bool,
+    /// Unit of the start and end dates, timestamp in milliseconds or block number.
+    pub unit: DeadlineUnit,
+    /// Asset of the funds, native currency or a PSP22 token.
+    pub asset: RewardAsset,
+    /// Stored state, it only changes when the round is closed or cancelled,
+    /// the current state is given by `Round::status_at`.
+    pub status: RoundStatus,
+    /// Team of the round, only its members vote and are rewarded, `None` for everyone.
+    pub team: Option<TeamId>,
+    /// Part of the value taken from the team budget, the rest comes from donations.
+    pub funded_by_team: Balance,
+    /// Number of voters, the contributors that cast or received a vote in the round.
+    pub voters_count: u32,
+    /// Votes cast in the round.
+    pub total_votes: u32,
+    /// Sum of the reputations of the voters, the close pays each one its share of it.
+    pub total_reputation: Reputation,
+    /// Tip paid to the keeper that started the close, if any.
+    pub keeper_tip: Balance,
+    /// Number of voters already paid while closing, from the start of `round_voters`.
+    pub settled_count: u32,
+    /// Rewards of the closed round not yet claimed, they remain reserved.
+    pub unclaimed: Balance,
+    /// End of the claim period of the rewards, in `unit`, set when the round is closed.
+    pub claims_expire_at: Timestamp,
+    /// Whether the badges of the closed round were issued.
+    pub badges_issued: bool,
+}
+
+impl Round {
+    /// State of the round at the given time (in the round unit), the voting window is
+    /// from `start_at` (included) to `finish_at` (excluded).
+    pub fn status_at(&self, now: Timestamp) -> RoundStatus {
+        match self.status {
+            RoundStatus::Closing | RoundStatus::Finished | RoundStatus::Cancelled => self.status,
+            _ if now < self.start_at => RoundStatus::Scheduled,
+            _ if now < self.finish_at => RoundStatus::Voting,
+            _ => RoundStatus::AwaitingClose,
+        }
+    }
+
+    /// Whether the claim period of the rewards has expired at the given time.
+    pub fn is_claim_expired_at(&self, now: Timestamp) -> bool {
+        now >= self.claims_expire_at
+    }
+}
+
+/// Information on a round with the fields computed at the query time.
+#[derive(Debug, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct RoundInfo {
+    /// Identifier of the round.
+    pub round_id: RoundId,
+    /// Stored information of the round.
+    pub round: Round,
+    /// Current state of the round.
+    pub status: RoundStatus,
+    /// Time until the end of the voting, in the round unit, zero once it ended.
+    pub time_remaining: Timestamp,
+}
+
+/// Administrative action that needs the approval of several administrators.
+#[derive(Debug, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum AdminAction {
+    OpenRound {
+        name: String,
+        value: Balance,
+        max_votes: VotesNumber,
+        start_at: Timestamp,
+        finish_at: Timestamp,
+        team: Option<TeamId>,
+        unit: Option<DeadlineUnit>,
+        asset: Option<RewardAsset>,
+    },
+    CloseRound(RoundId),
+    CancelRound(RoundId, String),
+    AmendRound {
+        round_id: RoundId,
+        value: Balance,
+        max_votes: VotesNumber,
+        finish_at: Timestamp,
+    },
+    SetRoundSchedule {
+        name: String,
+        period: Timestamp,
+        duration: Timestamp,
+        value: Balance,
+        max_votes: VotesNumber,
+        start_at: Timestamp,
+        asset: Option<RewardAsset>,
+    },
+    RemRoundSchedule,
+    AddAdmin(AccountId),
+    RemAdmin(AccountId),
+    GrantPermission(AccountId, Permission),
+    RevokePermission(AccountId, Permission),
+    SetApprovalThreshold(ApprovalsNumber),
+    SetCodeHash(Hash),
+    SetCloseGracePeriod(Timestamp),
+    SetCloseGraceBlocks(u32),
+    SetKeeperTipPerMille(u16),
+    SetClaimPeriod(Timestamp),
+    SetClaimBlocks(u32),
+    ImportMembers(Vec<(AccountId, Role)>),
+    SetTeamBudget(TeamId, Balance),
+    AddTeamLead(TeamId, AccountId),
+    RemTeamLead(TeamId, AccountId),
+}
+
+impl AdminAction {
+    /// Permission needed to propose and approve the action.
+    pub fn permission(&self) -> Permission {
+        match self {
+            AdminAction::OpenRound { .. }
+            | AdminAction::CloseRound(_)
+            | AdminAction::CancelRound(..)
+            | AdminAction::AmendRound { .. }
+            | AdminAction::SetRoundSchedule { .. }
+            | AdminAction::RemRoundSchedule => Permission::RoundManager,
+            AdminAction::AddAdmin(_)
+            | AdminAction::RemAdmin(_)
+            | AdminAction::GrantPermission(..)
+            | AdminAction::RevokePermission(..)
+            | AdminAction::ImportMembers(_)
+            | AdminAction::AddTeamLead(..)
+            | AdminAction::RemTeamLead(..) => Permission::MembershipManager,
+            AdminAction::SetTeamBudget(..) => Permission::Treasurer,
+            AdminAction::SetApprovalThreshold(_)
+            | AdminAction::SetCodeHash(_)
+            | AdminAction::SetCloseGracePeriod(_)
+            | AdminAction::SetCloseGraceBlocks(_)
+            | AdminAction::SetKeeperTipPerMille(_)
+            | AdminAction::SetClaimPeriod(_)
+            | AdminAction::SetClaimBlocks(_) => Permission::ParameterManager,
+        }
+    }
+}
+
+/// Information on an administrative action awaiting approval.
+#[derive(Debug, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct PendingAction {
+    /// Action to run once approved.
+    pub action: AdminAction,
+    /// Administrator who proposed the action.
+    pub proposer: AccountId,
+    /// Administrators who approved the action, the proposer included.
+    pub approvals: Vec<AccountId>,
+    /// Expiration date of the action (timestamp), in milliseconds.
+    pub expires_at: Timestamp,
+}
+
+/// Information on a pending application to become a contributor.
+#[derive(Debug, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Application {
+    /// Why the applicant wants to join the organization.
+    pub motivation: String,
+    /// Application date (timestamp), in milliseconds.
+    pub applied_at: Timestamp,
+}
+
+/// Public information of a member, shown by the user interfaces.
+#[derive(Debug, PartialEq, Eq, Clone, Default, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Profile {
+    /// Name to show instead of the address.
+    pub display_name: String,
+    /// Short name, for mentions.
+    pub handle: String,
+    /// URI of the avatar image.
+    pub avatar_uri: String,
+}
+
+/// Information on a team of contributors inside the organization.
+#[derive(Debug, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Team {
+    /// Name of the team.
+    pub name: String,
+    /// Funds available for the team rounds, each round consumes its value,
+    /// they are reserved in the treasury (native currency).
+    pub budget: Balance,
+    /// Number of members of the team.
+    pub members_count: u32,
+    /// Number of leads of the team.
+    pub leads_count: u32,
+}
+
+/// Recurring schedule of rounds, anyone can open the next round when it is due.
+#[derive(Debug, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct RoundSchedule {
+    /// Name of the rounds, followed by the number of the round in the schedule.
+    pub name: String,
+    /// Time between the start of consecutive rounds, in the organization deadline unit.
+    pub period: Timestamp,
+    /// Duration of the voting of each round, in the organization deadline unit.
+    pub duration: Timestamp,
+    /// Funds to be distributed in each round.
+    pub value: Balance,
+    /// Maximum number of votes per contributor in each round.
+    pub max_votes: VotesNumber,
+    /// Asset of the funds of each round.
+    pub asset: RewardAsset,
+    /// Date from which the next round can be opened, in the organization deadline unit.
+    pub next_start_at: Timestamp,
+    /// Last round opened by the schedule.
+    pub last_round_id: Option<RoundId>,
+    /// Number of rounds opened by the schedule.
+    pub rounds_count: u32,
+}
+
+/// Funds of the organization in an asset, the reserved funds belong to the rounds
+/// not yet closed and to the rewards not yet claimed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Treasury {
+    /// Balance of the contract.
+    pub total: Balance,
+    /// Funds reserved by the rounds not yet closed.
+    pub reserved: Balance,
+    /// Funds available for new rounds.
+    pub free: Balance,
+    /// Minimum balance the contract must keep (existential deposit), zero for the tokens.
+    pub existential_deposit: Balance,
 }
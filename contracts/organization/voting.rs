@@ -1,13 +1,18 @@
 use crate::errors::Error;
-use crate::types::{AccountId, Reputation, Vote};
+use crate::types::{AccountId, Reputation, RoundId, Vote};
 
 #[ink::trait_definition]
 pub trait VoteTrait {
-    /// Submit a vote, the caller gives the vote to `receiver_id`
+    /// Submit a vote in the round `round_id`, the caller gives the vote to `receiver_id`
     #[ink(message)]
-    fn submit_vote(&mut self, receiver_id: AccountId, vote: Vote) -> Result<(), Error>;
+    fn submit_vote(
+        &mut self,
+        round_id: RoundId,
+        receiver_id: AccountId,
+        vote: Vote,
+    ) -> Result<(), Error>;
 
-    /// Getting the reputation of a contributor, from whom it is consulted
+    /// Getting the reputation of a contributor in the round `round_id`, from whom it is consulted
     #[ink(message)]
-    fn get_reputation(&self) -> Result<Reputation, Error>;
+    fn get_reputation(&self, round_id: RoundId) -> Result<Reputation, Error>;
 }
@@ -0,0 +1,43 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+// https://openbrush.brushfam.io/
+// https://learn.brushfam.io/docs/openbrush
+
+pub use self::psp22::Psp22Ref;
+
+#[openbrush::implementation(PSP22, PSP22Metadata)] // ERC20 analogue
+#[openbrush::contract]
+pub mod psp22 {
+    use openbrush::traits::{Storage, String};
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Psp22 {
+        #[storage_field]
+        psp22: psp22::Data,
+
+        #[storage_field]
+        metadata: metadata::Data,
+    }
+
+    impl Psp22 {
+        #[ink(constructor)]
+        pub fn new(
+            total_supply: Balance,
+            name: Option<String>,
+            symbol: Option<String>,
+            decimals: u8,
+        ) -> Self {
+            let mut instance = Self::default();
+
+            instance.metadata.name.set(&name);
+            instance.metadata.symbol.set(&symbol);
+            instance.metadata.decimals.set(&decimals);
+
+            psp22::Internal::_mint_to(&mut instance, Self::env().caller(), total_supply)
+                .expect("mint of the total supply");
+
+            instance
+        }
+    }
+}